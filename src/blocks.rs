@@ -0,0 +1,55 @@
+/// Number of consecutive instances in a block. Swapped traces are permuted
+/// within a block, and stratified quotas are filled per block.
+pub const BLOCK_SIZE: u64 = 1000;
+
+/// Block index containing an instance.
+/// A trailing single-instance block is folded into its predecessor,
+/// since one instance cannot be deranged on its own.
+pub fn block_of(count: u64, instance_id: u64) -> u64 {
+    let block = instance_id / BLOCK_SIZE;
+    if count % BLOCK_SIZE == 1 && block == count / BLOCK_SIZE && block > 0 {
+        block - 1
+    } else {
+        block
+    }
+}
+
+/// Half-open range of instance ids in a block
+pub fn block_range(count: u64, block: u64) -> (u64, u64) {
+    let start = block * BLOCK_SIZE;
+    let mut end = (start + BLOCK_SIZE).min(count);
+    if count % BLOCK_SIZE == 1 && end + 1 == count {
+        end = count;
+    }
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn blocks_partition_the_instances() {
+        for count in [1, 2, 999, 1000, 1001, 1002, 2001, 2500] {
+            let mut next = 0;
+            while next < count {
+                let block = block_of(count, next);
+                let (start, end) = block_range(count, block);
+                assert_eq!(start, next, "count {}", count);
+                assert!((start..end).all(|idx| block_of(count, idx) == block), "count {}", count);
+                // Every block can be deranged
+                assert!(end - start >= 2 || count == 1, "count {} block {}", count, block);
+                next = end;
+            }
+            assert_eq!(next, count);
+        }
+    }
+    
+    #[test]
+    fn trailing_instance_is_folded_into_the_last_block() {
+        assert_eq!(block_of(2001, 2000), 1);
+        assert_eq!(block_range(2001, 1), (1000, 2001));
+        assert_eq!(block_range(1001, 0), (0, 1001));
+        assert_eq!(block_range(2002, 2), (2000, 2002));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::shards::line_idx;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Progress of a run, written next to the output file after every flush
+#[derive(Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// First instance id not yet known to be on disk
+    pub next_idx: u64,
+    /// Byte length of the output up to and including instance `next_idx - 1`
+    pub offset: u64,
+    /// Settings that determine the file's contents; resuming requires a match
+    pub config: String,
+}
+
+/// Sidecar checkpoint path for an output file
+pub fn checkpoint_path(output_path: &str) -> String {
+    format!("{}.ckpt", output_path)
+}
+
+/// Atomically replace the checkpoint (write to a temporary file, then rename).
+/// The temporary file and the directory are synced, so the new checkpoint
+/// survives a crash once this returns.
+pub fn save(output_path: &str, checkpoint: &Checkpoint) -> std::io::Result<()> {
+    let path = checkpoint_path(output_path);
+    let tmp_path = format!("{}.tmp", path);
+    let mut tmp = File::create(&tmp_path)?;
+    tmp.write_all(&serde_json::to_vec(checkpoint)?)?;
+    tmp.sync_all()?;
+    fs::rename(&tmp_path, &path)?;
+    
+    let dir = match Path::new(&path).parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()
+}
+
+/// Remove a stale checkpoint before a fresh run overwrites the output
+pub fn remove(output_path: &str) -> std::io::Result<()> {
+    match fs::remove_file(checkpoint_path(output_path)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Find where an interrupted run stopped and truncate the output there.
+///
+/// Starts from the checkpoint if there is one and the output agrees with it
+/// (the file reaches its offset, ending a line whose idx is `next_idx - 1`);
+/// otherwise the file is scanned from the start. A non-empty file without a
+/// checkpoint is refused unless `unverified` is set, in which case it is
+/// scanned from the start, expecting `range_start` as the first idx. Then extends over
+/// every further complete line whose idx continues the sequence. Anything
+/// after that, such as a partially written line, is truncated. Returns the
+/// next instance id to generate and the byte length of the kept output.
+pub fn find_resume_point(
+    output_path: &str,
+    range_start: u64,
+    config: &str,
+    unverified: bool,
+) -> Result<(u64, u64), Box<dyn std::error::Error>> {
+    let (mut next_idx, mut offset) = match fs::read(checkpoint_path(output_path)) {
+        Ok(bytes) => {
+            let checkpoint: Checkpoint = serde_json::from_slice(&bytes)?;
+            if checkpoint.config != config {
+                return Err(format!(
+                    "cannot resume {}: it was generated with different settings\n  checkpoint: {}\n  current:    {}",
+                    output_path, checkpoint.config, config
+                )
+                .into());
+            }
+            (checkpoint.next_idx, checkpoint.offset)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let written = fs::metadata(output_path).map_or(0, |metadata| metadata.len());
+            if written > 0 {
+                if !unverified {
+                    return Err(format!(
+                        "cannot resume {}: it has no checkpoint, so its settings cannot be checked \
+                         (pass --resume-unverified to scan it anyway)",
+                        output_path
+                    )
+                    .into());
+                }
+                eprintln!("Warning: no checkpoint for {}, scanning the whole file (settings are not verified)", output_path);
+            }
+            (range_start, 0)
+        }
+        Err(e) => return Err(e.into()),
+    };
+    
+    let mut file = match OpenOptions::new().read(true).write(true).open(output_path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && offset == 0 => return Ok((next_idx, 0)),
+        Err(e) => return Err(e.into()),
+    };
+    if offset > 0 && !ends_with_line(&mut file, offset, next_idx - 1)? {
+        eprintln!("Warning: {} does not match its checkpoint, scanning the whole file", output_path);
+        (next_idx, offset) = (range_start, 0);
+    }
+    
+    // Extend over complete lines written after the checkpoint
+    file.seek(SeekFrom::Start(offset))?;
+    let mut reader = BufReader::with_capacity(8 * 1024 * 1024, &file);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let read = reader.read_until(b'\n', &mut line)?;
+        if read == 0 || line.last() != Some(&b'\n') {
+            break;
+        }
+        match std::str::from_utf8(&line).ok().and_then(line_idx) {
+            Some(idx) if idx == next_idx => {
+                next_idx += 1;
+                offset += read as u64;
+            }
+            _ => break,
+        }
+    }
+    drop(reader);
+    
+    file.set_len(offset)?;
+    Ok((next_idx, offset))
+}
+
+/// Whether the first `end` bytes of the file end with a complete line for instance `idx`
+fn ends_with_line(file: &mut File, end: u64, idx: u64) -> std::io::Result<bool> {
+    const CHUNK: u64 = 64 * 1024;
+    if file.metadata()?.len() < end {
+        return Ok(false);
+    }
+    let mut last = [0u8];
+    file.seek(SeekFrom::Start(end - 1))?;
+    file.read_exact(&mut last)?;
+    if last[0] != b'\n' {
+        return Ok(false);
+    }
+    
+    // Walk back to the newline before the last line (or the start of the file)
+    let mut line_start = 0;
+    let mut chunk_end = end - 1;
+    let mut buf = Vec::new();
+    while chunk_end > 0 {
+        let chunk_start = chunk_end.saturating_sub(CHUNK);
+        buf.resize((chunk_end - chunk_start) as usize, 0);
+        file.seek(SeekFrom::Start(chunk_start))?;
+        file.read_exact(&mut buf)?;
+        if let Some(pos) = buf.iter().rposition(|&b| b == b'\n') {
+            line_start = chunk_start + pos as u64 + 1;
+            break;
+        }
+        chunk_end = chunk_start;
+    }
+    
+    // The idx field is at the very start of the line
+    let mut head = vec![0u8; (end - line_start).min(64) as usize];
+    file.seek(SeekFrom::Start(line_start))?;
+    file.read_exact(&mut head)?;
+    Ok(line_idx(&String::from_utf8_lossy(&head)) == Some(idx))
+}
+
+/// Open the output for appending after a resume point
+pub fn open_for_append(output_path: &str) -> std::io::Result<File> {
+    OpenOptions::new().append(true).create(true).open(output_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Output with instances 0..3 and a checkpoint claiming `next_idx` at `offset`
+    fn setup(name: &str, next_idx: u64, offset: u64) -> String {
+        let path = std::env::temp_dir().join(name).to_string_lossy().into_owned();
+        fs::write(&path, "{\"idx\":0,\"text\":\"a\"}\n{\"idx\":1,\"text\":\"b\"}\n{\"idx\":2,\"text\":\"c\"}\n{\"idx\":3,").unwrap();
+        save(&path, &Checkpoint { next_idx, offset, config: "test".into() }).unwrap();
+        path
+    }
+    
+    #[test]
+    fn resumes_from_a_matching_checkpoint() {
+        let path = setup("ckpt_matching.jsonl", 2, 42);
+        assert_eq!(find_resume_point(&path, 0, "test", false).unwrap(), (3, 63));
+        assert_eq!(fs::metadata(&path).unwrap().len(), 63);
+    }
+    
+    #[test]
+    fn scans_when_the_checkpoint_points_mid_record() {
+        let path = setup("ckpt_mid_record.jsonl", 2, 40);
+        assert_eq!(find_resume_point(&path, 0, "test", false).unwrap(), (3, 63));
+    }
+    
+    #[test]
+    fn scans_when_the_checkpoint_idx_does_not_match() {
+        let path = setup("ckpt_wrong_idx.jsonl", 3, 42);
+        assert_eq!(find_resume_point(&path, 0, "test", false).unwrap(), (3, 63));
+        
+        let path = setup("ckpt_past_end.jsonl", 9, 500);
+        assert_eq!(find_resume_point(&path, 0, "test", false).unwrap(), (3, 63));
+    }
+}
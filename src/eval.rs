@@ -0,0 +1,223 @@
+use crate::parser::{parse_text, rebuild_maze};
+use crate::solvers::{self, astar};
+use crate::trace_check::check_trace;
+use crate::types::Maze;
+use crate::SolverType;
+use clap::ValueEnum;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+/// Dataset lines are evaluated in chunks of this many instances
+const CHUNK_SIZE: usize = 10_000;
+
+/// Fields of a generated dataset line needed for evaluation
+#[derive(Deserialize)]
+pub struct DatasetRecord {
+    pub idx: u64,
+    pub text: String,
+    pub solver: String,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl DatasetRecord {
+    /// The solver that produced this line's trace
+    pub fn solver_type(&self) -> Result<SolverType, String> {
+        SolverType::from_str(&self.solver, true).map_err(|_| format!("unknown solver '{}'", self.solver))
+    }
+}
+
+/// A model output: the predicted text for one dataset instance
+#[derive(Deserialize)]
+struct PredictionRecord {
+    idx: u64,
+    text: String,
+}
+
+/// Per-instance evaluation result
+#[derive(Serialize, Default)]
+struct InstanceReport {
+    idx: u64,
+    has_prediction: bool,
+    plan_valid: bool,
+    plan_optimal: bool,
+    trace_exact: bool,
+    trace_valid: bool,
+    plan_length: usize,
+    optimal_length: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace_error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Aggregate counts over all evaluated instances
+#[derive(Default)]
+struct Summary {
+    instances: u64,
+    predictions: u64,
+    plan_valid: u64,
+    plan_optimal: u64,
+    trace_exact: u64,
+    trace_valid: u64,
+    both_valid: u64,
+    errors: u64,
+}
+
+impl Summary {
+    fn add(&mut self, report: &InstanceReport) {
+        self.instances += 1;
+        self.predictions += report.has_prediction as u64;
+        self.plan_valid += report.plan_valid as u64;
+        self.plan_optimal += report.plan_optimal as u64;
+        self.trace_exact += report.trace_exact as u64;
+        self.trace_valid += report.trace_valid as u64;
+        self.both_valid += (report.plan_valid && report.trace_valid) as u64;
+        self.errors += report.error.is_some() as u64;
+    }
+    
+    fn print(&self) {
+        let pct = |n: u64| 100.0 * n as f64 / self.instances.max(1) as f64;
+        println!("Instances:       {}", self.instances);
+        println!("With prediction: {} ({:.2}%)", self.predictions, pct(self.predictions));
+        println!("Plan valid:      {} ({:.2}%)", self.plan_valid, pct(self.plan_valid));
+        println!("Plan optimal:    {} ({:.2}%)", self.plan_optimal, pct(self.plan_optimal));
+        println!("Trace exact:     {} ({:.2}%)", self.trace_exact, pct(self.trace_exact));
+        println!("Trace valid:     {} ({:.2}%)", self.trace_valid, pct(self.trace_valid));
+        println!("Plan and trace:  {} ({:.2}%)", self.both_valid, pct(self.both_valid));
+        println!("Errors:          {} ({:.2}%)", self.errors, pct(self.errors));
+    }
+}
+
+/// Check that a plan walks from start to goal through adjacent floor cells
+fn plan_is_valid(maze: &Maze, plan: &[(usize, usize)]) -> bool {
+    if plan.first() != Some(&maze.start) || plan.last() != Some(&maze.goal) {
+        return false;
+    }
+    plan.iter().all(|&(x, y)| maze.get_cell(x, y))
+        && plan
+            .windows(2)
+            .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1)
+}
+
+/// Score one dataset instance against its prediction
+fn evaluate_instance(record: &DatasetRecord, prediction: Option<&str>) -> InstanceReport {
+    let mut report = InstanceReport {
+        idx: record.idx,
+        has_prediction: prediction.is_some(),
+        ..Default::default()
+    };
+    
+    let result = (|| -> Result<(), String> {
+        let original = parse_text(&record.text).map_err(|e| format!("dataset: {}", e))?;
+        let maze = rebuild_maze(&original, record.rows, record.cols)
+            .map_err(|e| format!("dataset: {}", e))?;
+        let solver = record.solver_type().map_err(|e| format!("dataset: {}", e))?;
+        
+        let optimal = astar::solve(&maze);
+        report.optimal_length = optimal.path.len();
+        
+        let Some(prediction) = prediction else {
+            return Ok(());
+        };
+        let predicted = parse_text(prediction).map_err(|e| format!("prediction: {}", e))?;
+        
+        report.plan_length = predicted.plan.len();
+        report.plan_valid = plan_is_valid(&maze, &predicted.plan);
+        report.plan_optimal = report.plan_valid && predicted.plan.len() == optimal.path.len();
+        
+        let reference = solvers::solve_maze(solver, &maze);
+        report.trace_exact = predicted.reasoning == reference.reasoning;
+        
+        match check_trace(solver, &maze, &predicted.reasoning) {
+            Ok(()) => report.trace_valid = true,
+            Err(violation) => report.trace_error = Some(violation.to_string()),
+        }
+        Ok(())
+    })();
+    
+    report.error = result.err();
+    report
+}
+
+/// Load predictions keyed by idx, so they may come in any order
+pub fn load_predictions(path: &str) -> Result<HashMap<u64, String>, Box<dyn std::error::Error>> {
+    let mut predictions = HashMap::new();
+    for (line_no, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: PredictionRecord = serde_json::from_str(&line)
+            .map_err(|e| format!("{}:{}: {}", path, line_no + 1, e))?;
+        predictions.insert(record.idx, record.text);
+    }
+    Ok(predictions)
+}
+
+/// Stream a dataset in chunks of `CHUNK_SIZE` records
+pub fn for_each_chunk<F>(path: &str, mut f: F) -> Result<(), Box<dyn std::error::Error>>
+where
+    F: FnMut(Vec<DatasetRecord>) -> Result<(), Box<dyn std::error::Error>>,
+{
+    let mut lines = BufReader::new(File::open(path)?).lines().enumerate();
+    
+    loop {
+        let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+        for (line_no, line) in lines.by_ref() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: DatasetRecord = serde_json::from_str(&line)
+                .map_err(|e| format!("{}:{}: {}", path, line_no + 1, e))?;
+            chunk.push(record);
+            if chunk.len() == CHUNK_SIZE {
+                break;
+            }
+        }
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        f(chunk)?;
+    }
+}
+
+/// Evaluate predictions against a generated dataset.
+/// Writes one report line per dataset instance and prints aggregate metrics.
+pub fn run(dataset_path: &str, predictions_path: &str, output_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let predictions = load_predictions(predictions_path)?;
+    
+    let mut writer = BufWriter::new(File::create(output_path)?);
+    let mut summary = Summary::default();
+    // Distinct idx values that had a prediction (the dataset may repeat an idx)
+    let mut matched = HashSet::new();
+    
+    for_each_chunk(dataset_path, |chunk| {
+        let reports: Vec<InstanceReport> = chunk
+            .par_iter()
+            .map(|record| evaluate_instance(record, predictions.get(&record.idx).map(String::as_str)))
+            .collect();
+        
+        for report in &reports {
+            summary.add(report);
+            if report.has_prediction {
+                matched.insert(report.idx);
+            }
+            serde_json::to_writer(&mut writer, report)?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(())
+    })?;
+    writer.flush()?;
+    
+    summary.print();
+    let unmatched = predictions.len() - matched.len();
+    if unmatched > 0 {
+        eprintln!("Warning: {} prediction(s) had no matching dataset idx", unmatched);
+    }
+    Ok(())
+}
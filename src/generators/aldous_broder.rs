@@ -0,0 +1,57 @@
+use super::room_grid;
+use crate::types::Maze;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Aldous-Broder maze generation (random walk, uniform spanning tree)
+/// Slow to finish but independent of Wilson's, so the two can cross-check each other.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+) -> Result<Maze, String> {
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let (offset, room_rows, room_cols) = room_grid(rng, rows, cols);
+    let num_rooms = room_rows * room_cols;
+    if num_rooms == 0 {
+        return Err(format!("aldous-broder: a {}x{} maze needs at least one room", rows, cols));
+    }
+    let mut in_maze = vec![false; num_rooms];
+    
+    // Directions between adjacent rooms (in room coordinates)
+    const DIRECTIONS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    
+    // Start the walk at a random room
+    let first = rng.gen_range(0..num_rooms);
+    let (mut rx, mut ry) = (first % room_cols, first / room_cols);
+    in_maze[first] = true;
+    maze.set_cell(offset + 2 * rx, offset + 2 * ry, true);
+    let mut visited = 1;
+    
+    // Walk until every room is in the maze, keeping the edge that first enters each room
+    while visited < num_rooms {
+        let (dx, dy) = DIRECTIONS[rng.gen_range(0..DIRECTIONS.len())];
+        let nx = rx as i32 + dx;
+        let ny = ry as i32 + dy;
+        
+        // Check bounds
+        if nx < 0 || nx >= room_cols as i32 || ny < 0 || ny >= room_rows as i32 {
+            continue;
+        }
+        let (nx, ny) = (nx as usize, ny as usize);
+        
+        let room = ny * room_cols + nx;
+        if !in_maze[room] {
+            in_maze[room] = true;
+            visited += 1;
+            maze.set_cell(offset + 2 * nx, offset + 2 * ny, true);
+            maze.set_cell(offset + rx + nx, offset + ry + ny, true);
+        }
+        rx = nx;
+        ry = ny;
+    }
+    
+    Ok(maze)
+}
@@ -0,0 +1,57 @@
+use super::room_grid;
+use crate::types::Maze;
+use crate::parameters::GeneratorParams;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Room steps (dx, dy) toward the corner named by the `bias` parameter
+pub fn bias_direction(bias: &str) -> (i32, i32) {
+    match bias {
+        "northeast" => (1, -1),
+        "southwest" => (-1, 1),
+        "southeast" => (1, 1),
+        _ => (-1, -1),
+    }
+}
+
+/// Binary tree maze generation
+/// Every room opens toward one of the two sides of the bias corner, leaving
+/// those two border corridors fully open and a strong diagonal drift.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+    params: &GeneratorParams,
+) -> Maze {
+    let (dx, dy) = bias_direction(params.get_choice("bias", "northwest"));
+    
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let (offset, room_rows, room_cols) = room_grid(rng, rows, cols);
+    
+    for ry in 0..room_rows {
+        for rx in 0..room_cols {
+            let x = offset + 2 * rx;
+            let y = offset + 2 * ry;
+            maze.set_cell(x, y, true);
+            
+            // Open toward the horizontal or vertical bias side, where one exists
+            let can_x = (0..room_cols as i32).contains(&(rx as i32 + dx));
+            let can_y = (0..room_rows as i32).contains(&(ry as i32 + dy));
+            let horizontal = match (can_x, can_y) {
+                (true, true) => rng.gen::<f64>() < 0.5,
+                (true, false) => true,
+                (false, true) => false,
+                (false, false) => continue,
+            };
+            if horizontal {
+                maze.set_cell((x as i32 + dx) as usize, y, true);
+            } else {
+                maze.set_cell(x, (y as i32 + dy) as usize, true);
+            }
+        }
+    }
+    
+    maze
+}
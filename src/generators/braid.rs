@@ -0,0 +1,78 @@
+use crate::types::Maze;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+const DIRECTIONS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Cell `steps` cells away in direction `dir`, if in bounds
+fn step(maze: &Maze, (x, y): (usize, usize), (dx, dy): (i32, i32), steps: i32) -> Option<(usize, usize)> {
+    let nx = x as i32 + dx * steps;
+    let ny = y as i32 + dy * steps;
+    if nx >= 0 && nx < maze.cols as i32 && ny >= 0 && ny < maze.rows as i32 {
+        Some((nx as usize, ny as usize))
+    } else {
+        None
+    }
+}
+
+fn is_floor(maze: &Maze, cell: Option<(usize, usize)>) -> bool {
+    cell.is_some_and(|(x, y)| maze.get_cell(x, y))
+}
+
+/// Floor cell with exactly one floor neighbor
+fn is_dead_end(maze: &Maze, cell: (usize, usize)) -> bool {
+    is_floor(maze, Some(cell))
+        && DIRECTIONS.iter().filter(|&&dir| is_floor(maze, step(maze, cell, dir, 1))).count() == 1
+}
+
+/// Braid a maze: open a wall at a `fraction` of its dead ends, adding loops.
+///
+/// Each chosen dead end knocks out a wall that separates it from another
+/// floor cell, preferring walls that also remove a second dead end. Only
+/// walls become floor, so start, goal and connectivity are unaffected.
+pub fn braid(rng: &mut Xoshiro256PlusPlus, maze: &mut Maze, fraction: f64) {
+    let mut dead_ends = Vec::new();
+    for y in 0..maze.rows {
+        for x in 0..maze.cols {
+            if is_dead_end(maze, (x, y)) {
+                dead_ends.push((x, y));
+            }
+        }
+    }
+    dead_ends.shuffle(rng);
+    
+    let target = (fraction * dead_ends.len() as f64).round() as usize;
+    let mut removed = 0;
+    let mut candidates = Vec::with_capacity(4);
+    
+    for &cell in &dead_ends {
+        if removed >= target {
+            break;
+        }
+        // An earlier opening may already have removed this dead end
+        if !is_dead_end(maze, cell) {
+            continue;
+        }
+        
+        // Walls with floor behind them, those joining two dead ends first
+        candidates.clear();
+        for &dir in &DIRECTIONS {
+            if let (Some(wall), Some(beyond)) = (step(maze, cell, dir, 1), step(maze, cell, dir, 2)) {
+                if !is_floor(maze, Some(wall)) && is_floor(maze, Some(beyond)) {
+                    candidates.push((wall, is_dead_end(maze, beyond)));
+                }
+            }
+        }
+        if candidates.iter().any(|&(_, joins_dead_end)| joins_dead_end) {
+            candidates.retain(|&(_, joins_dead_end)| joins_dead_end);
+        }
+        if candidates.is_empty() {
+            continue;
+        }
+        
+        let ((wx, wy), joins_dead_end) = candidates[rng.gen_range(0..candidates.len())];
+        maze.set_cell(wx, wy, true);
+        removed += 1 + joins_dead_end as usize;
+    }
+}
@@ -0,0 +1,117 @@
+use crate::types::Maze;
+use crate::parameters::GeneratorParams;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Rectangular area of the grid (x, y, width, height)
+#[derive(Clone, Copy)]
+struct Rect {
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+}
+
+/// Room size limits shared by every leaf
+struct RoomSize {
+    min: usize,
+    max: usize,
+}
+
+/// Split `rect` recursively, place a room in each leaf and join sibling subtrees
+/// with L-shaped corridors. Returns a floor cell of the subtree to connect to.
+fn build(
+    rng: &mut Xoshiro256PlusPlus,
+    maze: &mut Maze,
+    rect: Rect,
+    depth: usize,
+    size: &RoomSize,
+) -> (usize, usize) {
+    // Each child needs room for a minimum-size room plus its one-cell margin
+    let min_span = size.min + 1;
+    let can_split_x = rect.w >= 2 * min_span;
+    let can_split_y = rect.h >= 2 * min_span;
+    
+    if depth > 0 && (can_split_x || can_split_y) {
+        // Prefer cutting across the longer side
+        let vertical = match (can_split_x, can_split_y) {
+            (true, false) => true,
+            (false, true) => false,
+            _ if rect.w * 4 > rect.h * 5 => true,
+            _ if rect.h * 4 > rect.w * 5 => false,
+            _ => rng.gen::<f64>() < 0.5,
+        };
+        
+        let (first, second) = if vertical {
+            let split = rng.gen_range(min_span..=rect.w - min_span);
+            (
+                Rect { w: split, ..rect },
+                Rect { x: rect.x + split, w: rect.w - split, ..rect },
+            )
+        } else {
+            let split = rng.gen_range(min_span..=rect.h - min_span);
+            (
+                Rect { h: split, ..rect },
+                Rect { y: rect.y + split, h: rect.h - split, ..rect },
+            )
+        };
+        
+        let a = build(rng, maze, first, depth - 1, size);
+        let b = build(rng, maze, second, depth - 1, size);
+        carve_corridor(rng, maze, a, b);
+        return if rng.gen::<f64>() < 0.5 { a } else { b };
+    }
+    
+    // Leaf: a room inside the rect, keeping a wall margin on its top and left
+    let max_w = (rect.w - 1).max(1);
+    let max_h = (rect.h - 1).max(1);
+    let room_w = rng.gen_range(size.min.min(max_w)..=size.max.min(max_w));
+    let room_h = rng.gen_range(size.min.min(max_h)..=size.max.min(max_h));
+    let room_x = rect.x + rect.w - room_w - rng.gen_range(0..=max_w - room_w);
+    let room_y = rect.y + rect.h - room_h - rng.gen_range(0..=max_h - room_h);
+    
+    for y in room_y..room_y + room_h {
+        for x in room_x..room_x + room_w {
+            maze.set_cell(x, y, true);
+        }
+    }
+    (room_x + rng.gen_range(0..room_w), room_y + rng.gen_range(0..room_h))
+}
+
+/// Carve an L-shaped corridor between two cells, turning at a random corner
+fn carve_corridor(rng: &mut Xoshiro256PlusPlus, maze: &mut Maze, a: (usize, usize), b: (usize, usize)) {
+    let turn = if rng.gen::<f64>() < 0.5 { (b.0, a.1) } else { (a.0, b.1) };
+    
+    // Both legs are axis-aligned, so each box below is a single line
+    for (from, to) in [(a, turn), (turn, b)] {
+        for y in from.1.min(to.1)..=from.1.max(to.1) {
+            for x in from.0.min(to.0)..=from.0.max(to.0) {
+                maze.set_cell(x, y, true);
+            }
+        }
+    }
+}
+
+/// Rooms-and-corridors dungeon generation (binary space partitioning)
+/// Splits the grid `split_depth` times, places a rectangular room in each
+/// leaf and joins sibling subtrees with corridors, so every room is reachable.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+    params: &GeneratorParams,
+) -> Maze {
+    let min_room_size = params.get_int("min_room_size", 3) as usize;
+    let max_room_size = params.get_int("max_room_size", 8) as usize;
+    let split_depth = params.get_int("split_depth", 4) as usize;
+    
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let size = RoomSize { min: min_room_size, max: max_room_size };
+    // Leaving out the last row and column gives every room a wall on all four sides
+    let area = Rect { x: 0, y: 0, w: cols.saturating_sub(1).max(1), h: rows.saturating_sub(1).max(1) };
+    build(rng, &mut maze, area, split_depth, &size);
+    
+    maze
+}
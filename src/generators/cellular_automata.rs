@@ -0,0 +1,119 @@
+use crate::types::Maze;
+use crate::parameters::GeneratorParams;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Cellular-automata cave generation
+/// Fills the grid with random walls, smooths it with birth/survival rules
+/// over the 8 surrounding cells (out of bounds counts as wall), then by
+/// default keeps only the largest connected cave so start and goal are
+/// always reachable.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+    params: &GeneratorParams,
+) -> Result<Maze, String> {
+    let fill_prob = params.get("fill_prob", 0.45);
+    let iterations = params.get_int("iterations", 4) as usize;
+    let birth_limit = params.get_int("birth_limit", 5) as usize;
+    let survival_limit = params.get_int("survival_limit", 4) as usize;
+    let max_attempts = params.get_int("max_attempts", 100) as usize;
+    let keep_largest = params.get_bool("keep_largest", true);
+    
+    let total = rows * cols;
+    let mut walls = vec![false; total];
+    let mut next = vec![false; total];
+    let mut component = vec![usize::MAX; total];
+    let mut stack = Vec::with_capacity(total);
+    
+    for _ in 0..max_attempts {  // Retry until a cave has room for start and goal
+        // Random fill
+        for wall in walls.iter_mut() {
+            *wall = rng.gen::<f64>() < fill_prob;
+        }
+        
+        // Smoothing passes
+        for _ in 0..iterations {
+            for y in 0..rows {
+                for x in 0..cols {
+                    let mut wall_neighbors = 0;
+                    for dy in -1i32..=1 {
+                        for dx in -1i32..=1 {
+                            if dx == 0 && dy == 0 {
+                                continue;
+                            }
+                            let nx = x as i32 + dx;
+                            let ny = y as i32 + dy;
+                            if nx < 0 || nx >= cols as i32 || ny < 0 || ny >= rows as i32
+                                || walls[ny as usize * cols + nx as usize]
+                            {
+                                wall_neighbors += 1;
+                            }
+                        }
+                    }
+                    let idx = y * cols + x;
+                    next[idx] = if walls[idx] {
+                        wall_neighbors >= survival_limit
+                    } else {
+                        wall_neighbors >= birth_limit
+                    };
+                }
+            }
+            std::mem::swap(&mut walls, &mut next);
+        }
+        
+        // Label 4-connected floor components, remembering the largest
+        component.fill(usize::MAX);
+        let mut largest = (0, 0);  // (size, label)
+        for seed in 0..total {
+            if walls[seed] || component[seed] != usize::MAX {
+                continue;
+            }
+            let mut size = 0;
+            component[seed] = seed;
+            stack.push(seed);
+            while let Some(idx) = stack.pop() {
+                size += 1;
+                let (x, y) = (idx % cols, idx / cols);
+                let neighbors = [
+                    (x > 0).then(|| idx - 1),
+                    (x + 1 < cols).then(|| idx + 1),
+                    (y > 0).then(|| idx - cols),
+                    (y + 1 < rows).then(|| idx + cols),
+                ];
+                for n in neighbors.into_iter().flatten() {
+                    if !walls[n] && component[n] == usize::MAX {
+                        component[n] = seed;
+                        stack.push(n);
+                    }
+                }
+            }
+            if size > largest.0 {
+                largest = (size, seed);
+            }
+        }
+        
+        if largest.0 < 2 {
+            continue;
+        }
+        
+        // Keep only the largest cave, or every cave
+        let mut maze = Maze::new(rows, cols);
+        for y in 0..rows {
+            for x in 0..cols {
+                let idx = y * cols + x;
+                if component[idx] == largest.1 || (!keep_largest && !walls[idx]) {
+                    maze.set_cell(x, y, true);
+                }
+            }
+        }
+        return Ok(maze);
+    }
+    
+    Err(format!(
+        "cellular-automata: no {}x{} cave with at least 2 connected floor cells after {} attempts \
+         (lower fill_prob or the birth/survival limits, or raise max_attempts)",
+        rows, cols, max_attempts
+    ))
+}
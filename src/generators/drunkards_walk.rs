@@ -11,8 +11,8 @@ pub fn generate(
     cols: usize,
     params: &GeneratorParams,
 ) -> Maze {
-    // Get coverage parameter with validation
-    let coverage = params.get("coverage", 0.5).clamp(0.01, 1.0);
+    // Get coverage parameter (range checked during parameter parsing)
+    let coverage = params.get("coverage", 0.5);
     
     // Calculate target cells to carve (at least 2 for start/goal)
     let total_cells = rows * cols;
@@ -64,47 +64,22 @@ pub fn generate(
         }
     }
     
-    // Collect all floor cells for start/goal selection
-    let mut floors = Vec::with_capacity(carved);
-    for y in 0..rows {
-        for x in 0..cols {
-            if maze.get_cell(x, y) {
-                floors.push((x, y));
-            }
-        }
-    }
-    
-    // Edge case: ensure we have at least 2 cells
-    if floors.len() < 2 {
+    // Edge case: ensure we have at least 2 cells for start and goal
+    if carved < 2 {
         // This should be impossible with target >= 2, but be defensive
         // Carve one more cell if needed
-        for y in 0..rows {
+        'carve: for y in 0..rows {
             for x in 0..cols {
                 if !maze.get_cell(x, y) {
                     maze.set_cell(x, y, true);
-                    floors.push((x, y));
-                    if floors.len() >= 2 {
-                        break;
+                    carved += 1;
+                    if carved >= 2 {
+                        break 'carve;
                     }
                 }
             }
-            if floors.len() >= 2 {
-                break;
-            }
         }
     }
     
-    // Pick random distinct start and goal
-    let start_idx = rng.gen_range(0..floors.len());
-    let (start_x, start_y) = floors[start_idx];
-    
-    let mut goal_idx = rng.gen_range(0..floors.len());
-    while goal_idx == start_idx && floors.len() > 1 {
-        goal_idx = rng.gen_range(0..floors.len());
-    }
-    let (goal_x, goal_y) = floors[goal_idx];
-    
-    maze.start = (start_x, start_y);
-    maze.goal = (goal_x, goal_y);
     maze
 }
\ No newline at end of file
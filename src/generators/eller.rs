@@ -0,0 +1,117 @@
+use super::room_grid;
+use crate::types::Maze;
+use crate::parameters::GeneratorParams;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Union-Find over the set labels of one row (labels are 0..room_cols)
+struct RowSets {
+    parent: Vec<usize>,
+}
+
+impl RowSets {
+    fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Path compression
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+}
+
+/// Eller's algorithm for maze generation
+/// Builds the maze one room row at a time, keeping only O(cols) set state,
+/// so very tall mazes need no bookkeeping beyond the maze grid itself.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+    params: &GeneratorParams,
+) -> Maze {
+    let merge_prob = params.get("merge_prob", 0.5);
+    
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let (offset, room_rows, room_cols) = room_grid(rng, rows, cols);
+    
+    // Set label of each room in the current row; every room starts alone
+    let mut labels: Vec<usize> = (0..room_cols).collect();
+    let mut sets = RowSets { parent: (0..room_cols).collect() };
+    
+    // Per-row scratch space
+    let mut members: Vec<(usize, usize)> = Vec::with_capacity(room_cols);
+    let mut carried = vec![false; room_cols];
+    let mut used = vec![false; room_cols];
+    
+    for ry in 0..room_rows {
+        let y = offset + 2 * ry;
+        let last_row = ry + 1 == room_rows;
+        for rx in 0..room_cols {
+            maze.set_cell(offset + 2 * rx, y, true);
+        }
+        
+        // Join adjacent rooms in different sets (always on the last row)
+        for rx in 0..room_cols.saturating_sub(1) {
+            let a = sets.find(labels[rx]);
+            let b = sets.find(labels[rx + 1]);
+            if a != b && (last_row || rng.gen::<f64>() < merge_prob) {
+                sets.parent[b] = a;
+                maze.set_cell(offset + 2 * rx + 1, y, true);
+            }
+        }
+        if last_row {
+            break;
+        }
+        
+        // Group rooms by set, in column order within each set
+        members.clear();
+        for (rx, &label) in labels.iter().enumerate() {
+            members.push((sets.find(label), rx));
+        }
+        members.sort_unstable();
+        
+        // Every set extends down at least once; other rooms extend with probability 1/2
+        carried.fill(false);
+        for group in members.chunk_by(|a, b| a.0 == b.0) {
+            let mut any = false;
+            for &(_, rx) in group {
+                if rng.gen::<f64>() < 0.5 {
+                    carried[rx] = true;
+                    any = true;
+                }
+            }
+            if !any {
+                carried[group[rng.gen_range(0..group.len())].1] = true;
+            }
+        }
+        
+        // Carried rooms keep their set in the next row, the rest get unused labels
+        used.fill(false);
+        for &(root, rx) in &members {
+            labels[rx] = root;
+            if carried[rx] {
+                used[root] = true;
+                maze.set_cell(offset + 2 * rx, y + 1, true);
+            }
+        }
+        let mut free = (0..room_cols).filter(|&label| !used[label]);
+        for rx in 0..room_cols {
+            if !carried[rx] {
+                labels[rx] = free.next().unwrap();
+            }
+        }
+        for (label, parent) in sets.parent.iter_mut().enumerate() {
+            *parent = label;
+        }
+    }
+    
+    maze
+}
@@ -0,0 +1,96 @@
+use super::room_grid;
+use crate::types::Maze;
+use crate::parameters::GeneratorParams;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use std::collections::VecDeque;
+
+/// Growing-tree maze generation
+/// The cell selection policy moves between DFS-like (newest), Prim-like (random)
+/// and breadth-like (oldest) textures; `mix` picks newest with probability
+/// `newest_prob` and a random cell otherwise.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+    params: &GeneratorParams,
+) -> Result<Maze, String> {
+    let policy = params.get_choice("policy", "newest");
+    let newest_prob = params.get("newest_prob", 0.75);
+    
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let (offset, room_rows, room_cols) = room_grid(rng, rows, cols);
+    let num_rooms = room_rows * room_cols;
+    if num_rooms == 0 {
+        return Err(format!("growing-tree: a {}x{} maze needs at least one room", rows, cols));
+    }
+    
+    // Pick a random starting room
+    let first = rng.gen_range(0..num_rooms);
+    let start_x = offset + 2 * (first % room_cols);
+    let start_y = offset + 2 * (first / room_cols);
+    
+    // Active cells, oldest first
+    let mut active = VecDeque::with_capacity(rows * cols / 4);
+    active.push_back((start_x, start_y));
+    
+    // Mark starting cell as floor
+    maze.set_cell(start_x, start_y, true);
+    
+    // Directions for two-step jumps (to maintain wall structure)
+    const DIRECTIONS: [(i32, i32); 4] = [(0, -2), (2, 0), (0, 2), (-2, 0)];
+    
+    // Pre-allocate neighbors vector
+    let mut neighbors = Vec::with_capacity(4);
+    
+    while !active.is_empty() {
+        // Select an active cell according to the policy
+        let idx = match policy {
+            "newest" => active.len() - 1,
+            "oldest" => 0,
+            "random" => rng.gen_range(0..active.len()),
+            _ => {
+                if rng.gen::<f64>() < newest_prob {
+                    active.len() - 1
+                } else {
+                    rng.gen_range(0..active.len())
+                }
+            }
+        };
+        let (x, y) = active[idx];
+        
+        // Find unvisited neighbors (2 cells away)
+        neighbors.clear();
+        for &(dx, dy) in &DIRECTIONS {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+            
+            // Check bounds and whether still a wall
+            if nx >= 0 && nx < cols as i32 && ny >= 0 && ny < rows as i32
+                && !maze.get_cell(nx as usize, ny as usize)
+            {
+                neighbors.push((nx as usize, ny as usize));
+            }
+        }
+        
+        if !neighbors.is_empty() {
+            // Carve to a random unvisited neighbor and activate it
+            let &(nx, ny) = &neighbors[rng.gen_range(0..neighbors.len())];
+            maze.set_cell(nx, ny, true);
+            maze.set_cell((x + nx) / 2, (y + ny) / 2, true);
+            active.push_back((nx, ny));
+        } else {
+            // Fully explored, retire the cell; `random` ignores the order,
+            // so it can swap in the last cell instead of shifting the rest
+            if policy == "random" {
+                active.swap_remove_back(idx);
+            } else {
+                active.remove(idx);
+            }
+        }
+    }
+    
+    Ok(maze)
+}
@@ -0,0 +1,99 @@
+use super::room_grid;
+use crate::types::Maze;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Hunt-and-kill maze generation
+/// Random walks like DFS, but when a walk is stuck it scans for the first
+/// unvisited room next to the maze instead of backtracking along a stack.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+) -> Result<Maze, String> {
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let (offset, room_rows, room_cols) = room_grid(rng, rows, cols);
+    let num_rooms = room_rows * room_cols;
+    if num_rooms == 0 {
+        return Err(format!("hunt-and-kill: a {}x{} maze needs at least one room", rows, cols));
+    }
+    
+    // Pick a random starting room
+    let first = rng.gen_range(0..num_rooms);
+    let mut x = offset + 2 * (first % room_cols);
+    let mut y = offset + 2 * (first / room_cols);
+    
+    // Mark starting cell as floor
+    maze.set_cell(x, y, true);
+    
+    // Directions for two-step jumps (to maintain wall structure)
+    const DIRECTIONS: [(i32, i32); 4] = [(0, -2), (2, 0), (0, 2), (-2, 0)];
+    
+    // In-bounds rooms two cells away that are (or are not) already floor
+    let neighbors = |maze: &Maze, x: usize, y: usize, visited: bool, out: &mut Vec<(usize, usize)>| {
+        out.clear();
+        for &(dx, dy) in &DIRECTIONS {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+            if nx >= 0 && nx < cols as i32 && ny >= 0 && ny < rows as i32
+                && maze.get_cell(nx as usize, ny as usize) == visited
+            {
+                out.push((nx as usize, ny as usize));
+            }
+        }
+    };
+    
+    // Pre-allocate neighbors vector
+    let mut candidates = Vec::with_capacity(4);
+    
+    // Rows above this one hold no unvisited rooms
+    let mut hunt_row = offset;
+    
+    loop {
+        // Kill: walk to random unvisited neighbors until stuck
+        neighbors(&maze, x, y, false, &mut candidates);
+        if !candidates.is_empty() {
+            let (nx, ny) = candidates[rng.gen_range(0..candidates.len())];
+            maze.set_cell(nx, ny, true);
+            maze.set_cell((x + nx) / 2, (y + ny) / 2, true);
+            x = nx;
+            y = ny;
+            continue;
+        }
+        
+        // Hunt: first unvisited room (row by row) next to the maze
+        let mut found = None;
+        'hunt: for hy in (hunt_row..rows).step_by(2) {
+            let mut row_done = true;
+            for hx in (offset..cols).step_by(2) {
+                if maze.get_cell(hx, hy) {
+                    continue;
+                }
+                row_done = false;
+                neighbors(&maze, hx, hy, true, &mut candidates);
+                if !candidates.is_empty() {
+                    found = Some((hx, hy));
+                    break 'hunt;
+                }
+            }
+            if row_done {
+                hunt_row = hy + 2;
+            }
+        }
+        
+        let Some((hx, hy)) = found else {
+            break;
+        };
+        
+        // Connect it to a random visited neighbor and resume walking from it
+        let (px, py) = candidates[rng.gen_range(0..candidates.len())];
+        maze.set_cell(hx, hy, true);
+        maze.set_cell((hx + px) / 2, (hy + py) / 2, true);
+        x = hx;
+        y = hy;
+    }
+    
+    Ok(maze)
+}
@@ -3,24 +3,118 @@ pub mod dfs;
 pub mod kruskal;
 pub mod drunkards_walk;
 pub mod searchformer;
+pub mod prim;
+pub mod growing_tree;
+pub mod recursive_division;
+pub mod eller;
+pub mod aldous_broder;
+pub mod binary_tree;
+pub mod sidewinder;
+pub mod hunt_and_kill;
+pub mod braid;
+pub mod cellular_automata;
+pub mod bsp_dungeon;
 
 use crate::types::Maze;
-use crate::GeneratorType;
+use crate::placement::Placement;
+use crate::{GeneratorType, PlacementType};
 use crate::parameters::GeneratorParams;
+use rand::Rng;
 use rand_xoshiro::Xoshiro256PlusPlus;
 
+/// Generate one maze. `wall_bounds` is Searchformer's wall count range from
+/// `wall_bounds`; the other generators ignore it.
 pub fn generate_maze(
     generator: GeneratorType,
     rng: &mut Xoshiro256PlusPlus,
     rows: usize,
     cols: usize,
     params: &GeneratorParams,
-) -> Maze {
-    match generator {
+    placement: &Placement,
+    wall_bounds: (usize, usize),
+) -> Result<Maze, String> {
+    let mut maze = match generator {
         GeneratorType::Wilson => wilson::generate(rng, rows, cols),
         GeneratorType::Dfs => dfs::generate(rng, rows, cols),
         GeneratorType::Kruskal => kruskal::generate(rng, rows, cols),
         GeneratorType::DrunkardsWalk => drunkards_walk::generate(rng, rows, cols, params),
-        GeneratorType::Searchformer => searchformer::generate(rng, rows, cols),
+        GeneratorType::Searchformer => searchformer::generate(rng, rows, cols, params, wall_bounds)?,
+        GeneratorType::Prim => prim::generate(rng, rows, cols)?,
+        GeneratorType::GrowingTree => growing_tree::generate(rng, rows, cols, params)?,
+        GeneratorType::RecursiveDivision => recursive_division::generate(rng, rows, cols, params),
+        GeneratorType::Eller => eller::generate(rng, rows, cols, params),
+        GeneratorType::AldousBroder => aldous_broder::generate(rng, rows, cols)?,
+        GeneratorType::BinaryTree => binary_tree::generate(rng, rows, cols, params),
+        GeneratorType::Sidewinder => sidewinder::generate(rng, rows, cols, params),
+        GeneratorType::HuntAndKill => hunt_and_kill::generate(rng, rows, cols)?,
+        GeneratorType::CellularAutomata => cellular_automata::generate(rng, rows, cols, params)?,
+        GeneratorType::BspDungeon => bsp_dungeon::generate(rng, rows, cols, params),
+    };
+    
+    // Generator-agnostic post-processing; Searchformer braids before
+    // validating its path length
+    let braid = params.get("braid", 0.0);
+    if braid > 0.0 && !matches!(generator, GeneratorType::Searchformer) {
+        braid::braid(rng, &mut maze, braid);
+    }
+    
+    // Searchformer draws its own start and goal while validating path length
+    if !matches!(generator, GeneratorType::Searchformer) || placement.policy != PlacementType::Uniform {
+        placement.place(rng, &mut maze)?;
+    }
+    Ok(maze)
+}
+
+/// Check constraints between a generator's parameters that the per-parameter
+/// schema cannot express, before any output is written
+pub fn validate_params(generator: GeneratorType, params: &GeneratorParams) -> Result<(), String> {
+    match generator {
+        GeneratorType::Searchformer => {
+            let min_density = params.get("min_wall_density", 0.3);
+            let max_density = params.get("max_wall_density", 0.5);
+            if min_density > max_density {
+                return Err(format!(
+                    "searchformer: min_wall_density ({}) is greater than max_wall_density ({})",
+                    min_density, max_density
+                ));
+            }
+        }
+        GeneratorType::BspDungeon => {
+            let min_room_size = params.get_int("min_room_size", 3);
+            let max_room_size = params.get_int("max_room_size", 8);
+            if min_room_size > max_room_size {
+                return Err(format!(
+                    "bsp-dungeon: min_room_size ({}) is greater than max_room_size ({})",
+                    min_room_size, max_room_size
+                ));
+            }
+        }
+        _ => {}
     }
-}
\ No newline at end of file
+    Ok(())
+}
+
+/// Searchformer's wall count range on a `rows` x `cols` grid, resolved once
+/// per run: `total * density` rounded to the nearest cell. Legacy datasets
+/// sampled from the fixed 30-50% range in whole tenths of `total / 10`, so
+/// a `legacy` run that gives neither density keeps that range.
+pub fn wall_bounds(rows: usize, cols: usize, params: &GeneratorParams, legacy: bool) -> (usize, usize) {
+    let total = rows * cols;
+    if legacy && !params.contains("min_wall_density") && !params.contains("max_wall_density") {
+        return ((total / 10) * 3, (total / 10) * 5);
+    }
+    let wall_count = |density: f64| (total as f64 * density).round() as usize;
+    (wall_count(params.get("min_wall_density", 0.3)), wall_count(params.get("max_wall_density", 0.5)))
+}
+
+/// Random parity offset (0 or 1) shared by the room-based generators
+pub fn parity_offset(rng: &mut Xoshiro256PlusPlus) -> usize {
+    if rng.gen::<f64>() < 0.5 { 0 } else { 1 }
+}
+
+/// Random parity offset and the room grid it gives: room (rx, ry) sits at
+/// cell (offset + 2 * rx, offset + 2 * ry). Returns (offset, room_rows, room_cols).
+pub fn room_grid(rng: &mut Xoshiro256PlusPlus, rows: usize, cols: usize) -> (usize, usize, usize) {
+    let offset = parity_offset(rng);
+    (offset, rows.saturating_sub(offset).div_ceil(2), cols.saturating_sub(offset).div_ceil(2))
+}
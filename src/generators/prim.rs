@@ -0,0 +1,84 @@
+use super::room_grid;
+use crate::types::Maze;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Randomized Prim's algorithm for maze generation (frontier variant)
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+) -> Result<Maze, String> {
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let (offset, room_rows, room_cols) = room_grid(rng, rows, cols);
+    let num_rooms = room_rows * room_cols;
+    if num_rooms == 0 {
+        return Err(format!("prim: a {}x{} maze needs at least one room", rows, cols));
+    }
+    
+    // Room state: in the maze, or waiting in the frontier
+    let mut in_maze = vec![false; num_rooms];
+    let mut in_frontier = vec![false; num_rooms];
+    let mut frontier: Vec<usize> = Vec::with_capacity(num_rooms);
+    
+    // Directions between adjacent rooms (in room coordinates)
+    const DIRECTIONS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    
+    // Room ids of the in-bounds neighbors of a room
+    let neighbors = |room: usize| {
+        let rx = (room % room_cols) as i32;
+        let ry = (room / room_cols) as i32;
+        DIRECTIONS.iter().filter_map(move |&(dx, dy)| {
+            let nx = rx + dx;
+            let ny = ry + dy;
+            if nx >= 0 && nx < room_cols as i32 && ny >= 0 && ny < room_rows as i32 {
+                Some(ny as usize * room_cols + nx as usize)
+            } else {
+                None
+            }
+        })
+    };
+    let room_cell = |room: usize| (offset + (room % room_cols) * 2, offset + (room / room_cols) * 2);
+    
+    // Seed maze with one random room
+    let first = rng.gen_range(0..num_rooms);
+    in_maze[first] = true;
+    let (x, y) = room_cell(first);
+    maze.set_cell(x, y, true);
+    for n in neighbors(first) {
+        in_frontier[n] = true;
+        frontier.push(n);
+    }
+    
+    // Pre-allocate list of maze neighbors
+    let mut connected = Vec::with_capacity(4);
+    
+    // Grow the maze from random frontier rooms
+    while !frontier.is_empty() {
+        // Remove a random frontier room
+        let room = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        
+        // Connect it to a random neighbor already in the maze
+        connected.clear();
+        connected.extend(neighbors(room).filter(|&n| in_maze[n]));
+        let parent = connected[rng.gen_range(0..connected.len())];
+        
+        let (x, y) = room_cell(room);
+        let (px, py) = room_cell(parent);
+        maze.set_cell(x, y, true);
+        maze.set_cell((x + px) / 2, (y + py) / 2, true);
+        in_maze[room] = true;
+        
+        // Its unvisited neighbors join the frontier
+        for n in neighbors(room) {
+            if !in_maze[n] && !in_frontier[n] {
+                in_frontier[n] = true;
+                frontier.push(n);
+            }
+        }
+    }
+    
+    Ok(maze)
+}
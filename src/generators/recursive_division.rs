@@ -0,0 +1,76 @@
+use super::room_grid;
+use crate::types::Maze;
+use crate::parameters::GeneratorParams;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Recursive division maze generation (wall-adding)
+/// Starts from an open room grid and splits chambers with straight walls,
+/// each left with a single gap, until every chamber is one room wide.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+    params: &GeneratorParams,
+) -> Maze {
+    let horizontal_bias = params.get("horizontal_bias", 0.5);
+    
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let (offset, room_rows, room_cols) = room_grid(rng, rows, cols);
+    
+    // Open every room and every passage between adjacent rooms
+    for y in offset..offset + 2 * room_rows - 1 {
+        for x in offset..offset + 2 * room_cols - 1 {
+            if (x - offset) % 2 == 0 || (y - offset) % 2 == 0 {
+                maze.set_cell(x, y, true);
+            }
+        }
+    }
+    
+    // Chambers to divide, as (room x, room y, width, height) in room units
+    let mut stack = vec![(0, 0, room_cols, room_rows)];
+    
+    while let Some((rx, ry, width, height)) = stack.pop() {
+        if width < 2 && height < 2 {
+            continue;
+        }
+        
+        let horizontal = if width < 2 {
+            true
+        } else if height < 2 {
+            false
+        } else {
+            rng.gen::<f64>() < horizontal_bias
+        };
+        
+        if horizontal {
+            // Wall between room rows ry + split and ry + split + 1, with one gap
+            let split = rng.gen_range(0..height - 1);
+            let gap = rx + rng.gen_range(0..width);
+            let y = offset + 2 * (ry + split) + 1;
+            for room_x in rx..rx + width {
+                if room_x != gap {
+                    maze.set_cell(offset + 2 * room_x, y, false);
+                }
+            }
+            stack.push((rx, ry, width, split + 1));
+            stack.push((rx, ry + split + 1, width, height - split - 1));
+        } else {
+            // Wall between room columns rx + split and rx + split + 1, with one gap
+            let split = rng.gen_range(0..width - 1);
+            let gap = ry + rng.gen_range(0..height);
+            let x = offset + 2 * (rx + split) + 1;
+            for room_y in ry..ry + height {
+                if room_y != gap {
+                    maze.set_cell(x, offset + 2 * room_y, false);
+                }
+            }
+            stack.push((rx, ry, split + 1, height));
+            stack.push((rx + split + 1, ry, width - split - 1, height));
+        }
+    }
+    
+    maze
+}
@@ -1,33 +1,47 @@
+use super::braid;
 use crate::types::Maze;
+use crate::parameters::GeneratorParams;
 use crate::solvers::astar;
 use rand::Rng;
 use rand::seq::SliceRandom;
 use rand_xoshiro::Xoshiro256PlusPlus;
 
 /// SearchFormer Style maze generation
-/// Randomly samples walls (30-50% density) then validates with A*
+/// Randomly samples between `min_walls` and `max_walls` walls (see
+/// `generators::wall_bounds`) then validates with A*
 pub fn generate(
     rng: &mut Xoshiro256PlusPlus,
     rows: usize,
     cols: usize,
-) -> Maze {
+    params: &GeneratorParams,
+    (min_walls, max_walls): (usize, usize),
+) -> Result<Maze, String> {
     let total = rows * cols;
-    let base = total / 10;
-    let min_walls = base * 3;  // 30% walls
-    let max_walls = base * 5;  // 50% walls
+    let wall_sample_rate = params.get("wall_sample_rate", 0.0);
+    let max_attempts = params.get_int("max_attempts", 1000) as usize;
+    let braid = params.get("braid", 0.0);
+    
+    // 0 keeps the original requirement of spanning the larger dimension
+    let min_path_length = match params.get_int("min_path_length", 0) as usize {
+        0 => rows.max(cols),
+        n => n,
+    };
     
     // Create indices [0..total-1]
     let mut indices: Vec<usize> = (0..total).collect();
     
-    loop {  // Retry loop for entire maze generation
+    for _ in 0..max_attempts {  // Retry loop for entire maze generation
         // Shuffle indices
         indices.shuffle(rng);
         
-        // Pick random number of walls between 30-50%
+        // Pick random number of walls within the density range
         let num_walls = rng.gen_range(min_walls..=max_walls);
         
-        // First num_walls indices are walls, rest are passages
-        let passages = &indices[num_walls..];
+        // Randomly remove a fraction of the sampled walls again
+        let removed = (num_walls as f64 * wall_sample_rate).round() as usize;
+        
+        // First num_walls - removed indices are walls, rest are passages
+        let passages = &indices[num_walls - removed..];
         
         // Create maze with all walls first
         let mut maze = Maze::new(rows, cols);
@@ -39,9 +53,15 @@ pub fn generate(
             maze.set_cell(x, y, true);
         }
         
-        // Try up to 100 start/goal placements
-        let mut free_cells = passages.to_vec();
+        // Braid before validating, so min_path_length holds for the final maze
+        let mut free_cells: Vec<usize> = if braid > 0.0 {
+            braid::braid(rng, &mut maze, braid);
+            (0..total).filter(|&idx| maze.get_cell(idx % cols, idx / cols)).collect()
+        } else {
+            passages.to_vec()
+        };
         
+        // Try up to 100 start/goal placements
         for _ in 0..100 {
             // Shuffle before picking to actually try different start/goal combinations
             free_cells.shuffle(rng);
@@ -67,13 +87,45 @@ pub fn generate(
             
             // Check if path exists and is long enough
             if !solution.path.is_empty() && 
-               solution.path.len() >= rows.max(cols) {
+               solution.path.len() >= min_path_length {
                 // Success! Return this maze
-                return maze;
+                return Ok(maze);
             }
         }
         
         // Failed to find valid start/goal after 100 attempts
         // Loop will retry with new random walls
     }
-}
\ No newline at end of file
+    
+    Err(format!(
+        "searchformer: no {}x{} maze with a path of at least {} cells after {} attempts \
+         (lower min_path_length or the wall density, or raise max_attempts)",
+        rows, cols, min_path_length, max_attempts
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::get_generator_params;
+    use crate::generators::wall_bounds;
+    use crate::GeneratorType;
+    use rand::SeedableRng;
+    
+    fn wall_count_with(pairs: &[(&str, &str)]) -> usize {
+        let pairs = pairs.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect();
+        let params = GeneratorParams::parse(&get_generator_params(GeneratorType::Searchformer), pairs, "searchformer").unwrap();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+        let maze = generate(&mut rng, 20, 20, &params, wall_bounds(20, 20, &params, false)).unwrap();
+        (0..20 * 20).filter(|&idx| !maze.get_cell(idx % 20, idx / 20)).count()
+    }
+    
+    #[test]
+    fn wall_sample_rate_thins_sampled_walls() {
+        let density = [("min_wall_density", "0.4"), ("max_wall_density", "0.4"), ("min_path_length", "1")];
+        assert_eq!(wall_count_with(&density), 160);
+        
+        let thinned = [density.as_slice(), &[("wall_sample_rate", "0.25")]].concat();
+        assert_eq!(wall_count_with(&thinned), 120);
+    }
+}
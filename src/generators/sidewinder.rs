@@ -0,0 +1,63 @@
+use super::room_grid;
+use crate::types::Maze;
+use crate::parameters::GeneratorParams;
+use crate::generators::binary_tree::bias_direction;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Sidewinder maze generation
+/// Each room row is cut into horizontal runs heading toward the bias corner,
+/// and every run opens once toward the bias side. The border row on that
+/// side is one open corridor.
+pub fn generate(
+    rng: &mut Xoshiro256PlusPlus,
+    rows: usize,
+    cols: usize,
+    params: &GeneratorParams,
+) -> Maze {
+    let (dx, dy) = bias_direction(params.get_choice("bias", "northwest"));
+    
+    // Initialize maze with all walls
+    let mut maze = Maze::new(rows, cols);
+    
+    let (offset, room_rows, room_cols) = room_grid(rng, rows, cols);
+    
+    // Room columns in the order runs grow
+    let columns: Vec<usize> = if dx > 0 {
+        (0..room_cols).collect()
+    } else {
+        (0..room_cols).rev().collect()
+    };
+    let border_row = if dy < 0 { 0 } else { room_rows - 1 };
+    
+    // Pre-allocate the current run
+    let mut run = Vec::with_capacity(room_cols);
+    
+    for ry in 0..room_rows {
+        let y = offset + 2 * ry;
+        run.clear();
+        for (i, &rx) in columns.iter().enumerate() {
+            let x = offset + 2 * rx;
+            maze.set_cell(x, y, true);
+            run.push(x);
+            
+            let at_end = i + 1 == columns.len();
+            if ry == border_row {
+                // The border row is a single corridor
+                if !at_end {
+                    maze.set_cell((x as i32 + dx) as usize, y, true);
+                }
+            } else if !at_end && rng.gen::<f64>() < 0.5 {
+                // Extend the run
+                maze.set_cell((x as i32 + dx) as usize, y, true);
+            } else {
+                // Close the run, opening one of its rooms toward the bias side
+                let exit_x = run[rng.gen_range(0..run.len())];
+                maze.set_cell(exit_x, (y as i32 + dy) as usize, true);
+                run.clear();
+            }
+        }
+    }
+    
+    maze
+}
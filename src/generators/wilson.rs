@@ -1,3 +1,4 @@
+use super::parity_offset;
 use crate::types::Maze;
 use rand::Rng;
 use rand_xoshiro::Xoshiro256PlusPlus;
@@ -13,7 +14,7 @@ pub fn generate(
     let mut maze = Maze::new(rows, cols);
     
     // Parity offset for rooms (alternates between 0 and 1)
-    let offset = if rng.gen::<f64>() < 0.5 { 0 } else { 1 };
+    let offset = parity_offset(rng);
     
     // Build list of room coordinates (cells at odd positions)
     let mut rooms = Vec::new();
@@ -107,26 +108,5 @@ pub fn generate(
         }
     }
     
-    // Pick random distinct start and goal from floor cells
-    let mut floors = Vec::new();
-    for y in 0..rows {
-        for x in 0..cols {
-            if maze.get_cell(x, y) {
-                floors.push((x, y));
-            }
-        }
-    }
-    
-    let start_idx = rng.gen_range(0..floors.len());
-    let (start_x, start_y) = floors[start_idx];
-    
-    let mut goal_idx = rng.gen_range(0..floors.len());
-    while goal_idx == start_idx {
-        goal_idx = rng.gen_range(0..floors.len());
-    }
-    let (goal_x, goal_y) = floors[goal_idx];
-    
-    maze.start = (start_x, start_y);
-    maze.goal = (goal_x, goal_y);
     maze
 }
\ No newline at end of file
@@ -1,23 +1,41 @@
+mod blocks;
+mod checkpoint;
+mod eval;
 mod generators;
 mod parameters;
+mod parser;
+mod placement;
 mod prng;
 mod serializer;
+mod shards;
 mod solvers;
+mod stratify;
+mod trace_check;
+mod traces;
 mod types;
+#[cfg(test)]
+mod ust_check;
 
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use indicatif::{ProgressBar, ProgressStyle};
 use rayon::prelude::*;
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{BufWriter, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::mpsc::sync_channel;
 use std::thread;
 use std::time::Instant;
 
-use crate::parameters::{GeneratorParams, print_param_help, print_all_params_help};
-use crate::prng::create_instance_prng;
+use crate::parameters::{
+    generator_name, get_generator_params, get_placement_params, get_transform_params,
+    placement_name, print_all_params_help, print_param_help, transform_name, GeneratorParams,
+};
+use crate::placement::Placement;
+use crate::prng::{create_instance_prng, has_legacy_seeds};
 use crate::serializer::process_batch;
-use crate::types::MazeResult;
+use crate::traces::TraceTransform;
+use crate::types::{Maze, MazeResult, Solution};
 
 #[derive(Clone, Copy, Debug, ValueEnum, Hash)]
 pub enum GeneratorType {
@@ -26,18 +44,142 @@ pub enum GeneratorType {
     Wilson,
     Searchformer,
     DrunkardsWalk,
+    Prim,
+    GrowingTree,
+    RecursiveDivision,
+    Eller,
+    AldousBroder,
+    BinaryTree,
+    Sidewinder,
+    HuntAndKill,
+    CellularAutomata,
+    BspDungeon,
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum, Hash)]
 pub enum SolverType {
     #[value(name = "astar")]
     AStar,
+    /// Breadth-first search (enqueue/dequeue trace with depths)
+    Bfs,
+    /// Dijkstra's algorithm (A* trace format with h always 0)
+    Dijkstra,
+    /// Greedy best-first search (ordered by h only; plans may be suboptimal)
+    Greedy,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SeedScheme {
+    /// SplitMix64 over the master seed and stable generator/solver/instance ids
+    V1,
+    /// std DefaultHasher derivation used before v1 (toolchain dependent)
+    Legacy,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TraceMode {
+    /// Each maze carries its own reasoning trace
+    Original,
+    /// Each maze carries the reasoning trace of another instance
+    Swapped,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum PlacementType {
+    /// Two distinct floor cells chosen uniformly at random
+    Uniform,
+    /// Endpoints of a longest shortest path (double BFS)
+    Diameter,
+    /// Goal within a shortest-path distance range of the start
+    Band,
+    /// Goal at exactly a given shortest-path distance from the start
+    ExactDistance,
+    /// Start and goal both at dead ends
+    DeadEnds,
+    /// Start and goal nearest opposite corners or opposite sides
+    Opposite,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StratifyBy {
+    /// Number of cells in the optimal plan
+    PathLength,
+    /// Number of events in the solver's reasoning trace
+    TraceLength,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TransformType {
+    /// Leave traces untouched
+    None,
+    /// Shuffle the order of a fraction of events
+    Shuffle,
+    /// Remove a random fraction of events
+    Drop,
+    /// Cut a fraction of events from the end
+    Truncate,
+    /// Change g or h values (BFS: depths) of a fraction of events
+    Perturb,
+    /// Insert events at random cells
+    Inject,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Score predicted plans and traces against a generated dataset
+    Eval {
+        /// Generated dataset (JSONL)
+        #[arg(long)]
+        dataset: String,
+        
+        /// Model predictions (JSONL with `idx` and `text` fields)
+        #[arg(long)]
+        predictions: String,
+        
+        /// Per-instance report file
+        #[arg(short, long, default_value = "eval.jsonl")]
+        output: String,
+    },
+    
+    /// Check that reasoning traces are valid A* executions
+    CheckTraces {
+        /// Generated dataset (JSONL); its own traces are checked unless predictions are given
+        #[arg(long)]
+        dataset: String,
+        
+        /// Model predictions (JSONL with `idx` and `text` fields)
+        #[arg(long)]
+        predictions: Option<String>,
+        
+        /// Per-instance report file
+        #[arg(short, long, default_value = "trace_check.jsonl")]
+        output: String,
+    },
+    
+    /// Concatenate shard files in idx order and verify the result is complete
+    Merge {
+        /// Shard files, in any order
+        #[arg(required = true)]
+        inputs: Vec<String>,
+        
+        /// Merged dataset; if omitted the shards are only verified
+        #[arg(short, long)]
+        output: Option<String>,
+        
+        /// Expected total number of instances
+        #[arg(short, long)]
+        count: Option<u64>,
+    },
 }
 
 #[derive(Parser)]
 #[command(name = "maze_gen_fast")]
 #[command(about = "Parallel maze generation with hierarchical PRNG")]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    
     /// Generator algorithm
     #[arg(short, long, value_enum, required_unless_present = "list_params")]
     generator: Option<GeneratorType>,
@@ -50,6 +192,10 @@ struct Args {
     #[arg(long, default_value = "42")]
     seed: u64,
     
+    /// Per-instance seed derivation; use `legacy` to reproduce datasets made before v1
+    #[arg(long, value_enum, default_value = "v1")]
+    seed_scheme: SeedScheme,
+    
     /// Number of mazes to generate
     #[arg(short, long, required_unless_present = "list_params")]
     count: Option<u64>,
@@ -66,14 +212,66 @@ struct Args {
     #[arg(short, long, default_value = "output.jsonl")]
     output: String,
     
+    /// Split the instance ids into this many contiguous shards
+    #[arg(long, default_value = "1")]
+    num_shards: u64,
+    
+    /// Generate only this shard (0-based); shard files concatenate into the full dataset
+    #[arg(long, default_value = "0")]
+    shard_index: u64,
+    
+    /// Continue an interrupted run: keep the complete lines already in the output and generate the rest
+    #[arg(long)]
+    resume: bool,
+    
+    /// With --resume, continue an output that has no checkpoint without checking its settings
+    #[arg(long, requires = "resume")]
+    resume_unverified: bool,
+    
     /// Number of threads (defaults to all cores)
     #[arg(short, long)]
     threads: Option<usize>,
     
+    /// Which instance's reasoning trace to write with each maze
+    #[arg(long, value_enum, default_value = "original")]
+    trace_mode: TraceMode,
+    
+    /// Corruption applied to each reasoning trace after solving
+    #[arg(long, value_enum, default_value = "none")]
+    trace_transform: TransformType,
+    
+    /// Trace transform parameters as key=value pairs
+    #[arg(long = "trace-param", value_parser = parse_key_val::<String, String>)]
+    trace_params: Vec<(String, String)>,
+    
     /// Generator parameters as key=value pairs
     #[arg(long = "param", value_parser = parse_key_val::<String, String>)]
     params: Vec<(String, String)>,
     
+    /// How start and goal are chosen on the generated maze
+    #[arg(long, value_enum, default_value = "uniform")]
+    placement: PlacementType,
+    
+    /// Placement parameters as key=value pairs
+    #[arg(long = "placement-param", value_parser = parse_key_val::<String, String>)]
+    placement_params: Vec<(String, String)>,
+    
+    /// Fill per-bucket quotas of this length instead of keeping every maze
+    #[arg(long, value_enum)]
+    stratify: Option<StratifyBy>,
+    
+    /// Bucket boundaries for --stratify, e.g. 10,20,40,80 for [10,20) [20,40) [40,80)
+    #[arg(long, value_delimiter = ',', requires = "stratify")]
+    bucket_edges: Vec<u64>,
+    
+    /// Relative share of each bucket (defaults to equal shares)
+    #[arg(long, value_delimiter = ',', requires = "stratify")]
+    bucket_weights: Vec<f64>,
+    
+    /// Candidates to try before giving up on the quotas, split evenly over blocks of 1000 mazes (defaults to 100 per maze)
+    #[arg(long, requires = "stratify")]
+    max_candidates: Option<u64>,
+    
     /// List parameters for a specific generator or all generators
     #[arg(long, value_name = "GENERATOR")]
     list_params: Option<Option<GeneratorType>>,
@@ -96,6 +294,17 @@ where
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
     
+    // Handle subcommands
+    if let Some(command) = args.command {
+        return match command {
+            Command::Eval { dataset, predictions, output } => eval::run(&dataset, &predictions, &output),
+            Command::CheckTraces { dataset, predictions, output } => {
+                trace_check::run(&dataset, predictions.as_deref(), &output)
+            }
+            Command::Merge { inputs, output, count } => shards::merge(&inputs, output.as_deref(), count),
+        };
+    }
+    
     // Handle --list-params
     if let Some(maybe_generator) = args.list_params {
         match maybe_generator {
@@ -111,7 +320,114 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let count = args.count.expect("count required");
     
     // Parse generator parameters
-    let generator_params = GeneratorParams::from_vec(args.params)?;
+    let generator_params = GeneratorParams::parse(
+        &get_generator_params(generator),
+        args.params,
+        &format!("generator '{}'", generator_name(generator)),
+    )?;
+    generators::validate_params(generator, &generator_params)?;
+    
+    let placement_params = GeneratorParams::parse(
+        &get_placement_params(args.placement),
+        args.placement_params,
+        &format!("placement '{}'", placement_name(args.placement)),
+    )?;
+    let placement = Placement::new(args.placement, &placement_params)?;
+    
+    let trace_params = GeneratorParams::parse(
+        &get_transform_params(args.trace_transform),
+        args.trace_params,
+        &format!("trace transform '{}'", transform_name(args.trace_transform)),
+    )?;
+    let trace_transform = match args.trace_transform {
+        TransformType::None => None,
+        transform => Some(TraceTransform::new(transform, &trace_params)),
+    };
+    
+    // Everything that determines the output bytes, to check a resumed run matches
+    let mut run_config = format!(
+        "generator={} solver={:?} seed={} seed_scheme={:?} count={} rows={} cols={} shard={}/{} \
+         trace_mode={:?} trace_transform={:?} params={} trace_params={} \
+         placement={:?} placement_params={}",
+        generator_name(generator), solver, args.seed, args.seed_scheme, count, args.rows, args.cols,
+        args.shard_index, args.num_shards, args.trace_mode, args.trace_transform,
+        generator_params, trace_params, args.placement, placement_params,
+    );
+    if let Some(by) = args.stratify {
+        run_config.push_str(&format!(
+            " stratify={:?} bucket_edges={:?} bucket_weights={:?} max_candidates={:?}",
+            by, args.bucket_edges, args.bucket_weights, args.max_candidates
+        ));
+    }
+    
+    let strata = match args.stratify {
+        Some(by) => {
+            let max_candidates = args.max_candidates.unwrap_or(count.saturating_mul(100));
+            Some(stratify::Strata::new(by, args.bucket_edges, args.bucket_weights, count, max_candidates)?)
+        }
+        None => None,
+    };
+    
+    let trace_mode = args.trace_mode;
+    if trace_mode == TraceMode::Swapped && count < 2 {
+        return Err("--trace-mode swapped requires --count of at least 2".into());
+    }
+    
+    // Solvers index cells and write coordinates and costs as u32, and step in i32
+    let max_side = i32::MAX as usize;
+    if args.rows > max_side || args.cols > max_side || args.rows * args.cols > u32::MAX as usize {
+        return Err(format!(
+            "mazes are limited to {} rows or columns and {} cells (got {}x{})",
+            max_side, u32::MAX, args.rows, args.cols
+        )
+        .into());
+    }
+    
+    // Legacy seeds predate every solver but A* and every generator after
+    // drunkards-walk, so nothing else has legacy datasets
+    if args.seed_scheme == SeedScheme::Legacy && !matches!(solver, SolverType::AStar) {
+        return Err("--seed-scheme legacy only supports --solver astar".into());
+    }
+    if args.seed_scheme == SeedScheme::Legacy && !has_legacy_seeds(generator) {
+        return Err(format!(
+            "--seed-scheme legacy only supports the original generators (dfs, kruskal, wilson, searchformer, drunkards-walk), not {}",
+            generator_name(generator)
+        )
+        .into());
+    }
+    
+    if args.num_shards == 0 || args.shard_index >= args.num_shards {
+        return Err(format!(
+            "--shard-index must be below --num-shards (got {} of {})",
+            args.shard_index, args.num_shards
+        )
+        .into());
+    }
+    let (range_start, range_end) = shards::shard_range(count, args.num_shards, args.shard_index);
+    let shard_count = range_end - range_start;
+    
+    // Pick up after the last complete line of an interrupted run
+    let (resume_start, resume_offset) = if args.resume {
+        checkpoint::find_resume_point(&args.output, range_start, &run_config, args.resume_unverified)?
+    } else {
+        checkpoint::remove(&args.output)?;
+        (range_start, 0)
+    };
+    if resume_start == range_end {
+        if args.resume {
+            println!("{} is already complete ({} mazes)", args.output, shard_count);
+        } else {
+            // An empty shard still leaves an empty file, not whatever was there before
+            File::create(&args.output)?;
+            checkpoint::save(&args.output, &checkpoint::Checkpoint {
+                next_idx: range_end,
+                offset: 0,
+                config: run_config,
+            })?;
+            println!("Shard {}/{} has no instances; wrote empty {}", args.shard_index, args.num_shards, args.output);
+        }
+        return Ok(());
+    }
     
     // Set thread pool size if specified
     if let Some(threads) = args.threads {
@@ -122,104 +438,218 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     // Start timing
     let start_time = Instant::now();
-    println!("Generating {} mazes...", count);
+    if args.num_shards > 1 {
+        println!(
+            "Generating shard {}/{}: {} mazes (idx {}..{})...",
+            args.shard_index, args.num_shards, shard_count, range_start, range_end
+        );
+    } else {
+        println!("Generating {} mazes...", count);
+    }
+    if resume_start > range_start {
+        println!("Resuming at idx {} ({} mazes already written)", resume_start, resume_start - range_start);
+    }
+    
+    let seed = args.seed;
+    let seed_scheme = args.seed_scheme;
+    let rows = args.rows;
+    let cols = args.cols;
+    let wall_bounds = generators::wall_bounds(rows, cols, &generator_params, seed_scheme == SeedScheme::Legacy);
+    
+    // Generate and solve the maze seeded by one instance (or candidate) id
+    let solve_candidate = |candidate: u64| -> Result<(Maze, Solution), String> {
+        // Create instance PRNG
+        let mut rng = create_instance_prng(seed_scheme, seed, generator, candidate);
+        
+        // Generate maze
+        let maze = generators::generate_maze(generator, &mut rng, rows, cols, &generator_params, &placement, wall_bounds)?;
+        
+        // Solve maze
+        let solution = solvers::solve_maze(solver, &maze);
+        Ok((maze, solution))
+    };
     
     // Create progress bar for writing only
-    let writing_progress = ProgressBar::new(count);
+    let writing_progress = ProgressBar::new(shard_count);
     writing_progress.set_style(
         ProgressStyle::default_bar()
             .template("[{elapsed_precise}] {bar:40.green/red} {pos}/{len} mazes written ({per_sec})")?
             .progress_chars("##-"),
     );
+    writing_progress.set_position(resume_start - range_start);
     
     // Create bounded channel with larger capacity for batches
-    let (tx, rx) = sync_channel::<Vec<u8>>(100);
+    let (tx, rx) = sync_channel::<(u64, Vec<u8>)>(100);
     
     
     // Batch size for processing
+    // Batches are aligned to multiples of BATCH_SIZE over the whole dataset,
+    // so the first and last batch of a shard or resumed run may be partial.
     const BATCH_SIZE: usize = 1000;
+    let first_batch = resume_start / BATCH_SIZE as u64;
+    let num_batches = range_end.div_ceil(BATCH_SIZE as u64) - first_batch;
+    let batch_range = move |batch_idx: u64| -> (u64, u64) {
+        let batch_start = (first_batch + batch_idx) * BATCH_SIZE as u64;
+        (batch_start.max(resume_start), (batch_start + BATCH_SIZE as u64).min(range_end))
+    };
     
     // Writer thread
+    // Batches arrive in completion order; a reorder buffer writes them by
+    // batch index so the file is identical for any thread count.
+    // A sidecar checkpoint is saved before the first batch and after each
+    // flush, recording the settings and how far the file is complete, so an
+    // interrupted run can be resumed. The output is synced to disk before
+    // each checkpoint, so a checkpoint never points past data that was lost. The final one is kept, so resuming a
+    // finished run is a verified no-op.
     let output_path = args.output.clone();
     let writer_handle = thread::spawn(move || -> Result<(), std::io::Error> {
-        let file = File::create(&output_path)?;
+        let file = if resume_offset > 0 {
+            checkpoint::open_for_append(&output_path)?
+        } else {
+            File::create(&output_path)?
+        };
         let mut writer = BufWriter::with_capacity(8 * 1024 * 1024, file);
         
-        let mut total_written = 0u64;
-        for batch_bytes in rx {
-            writer.write_all(&batch_bytes)?;
-            total_written += BATCH_SIZE as u64;
-            
-            // Update progress less frequently
-            if total_written % 10_000 == 0 {
-                writing_progress.set_position(total_written.min(writing_progress.length().unwrap_or(total_written)));
-            }
+        let mut offset = resume_offset;
+        let save_checkpoint = |next_idx: u64, offset: u64| {
+            checkpoint::save(&output_path, &checkpoint::Checkpoint {
+                next_idx,
+                offset,
+                config: run_config.clone(),
+            })
+        };
+        save_checkpoint(resume_start, offset)?;
+        
+        let mut pending: BTreeMap<u64, Vec<u8>> = BTreeMap::new();
+        let mut next_batch = 0u64;
+        let mut next_idx = resume_start;
+        for (batch_idx, batch_bytes) in rx {
+            pending.insert(batch_idx, batch_bytes);
             
-            // Periodic flush
-            if total_written % 10_000 == 0 {
-                writer.flush()?;
+            while let Some(batch_bytes) = pending.remove(&next_batch) {
+                writer.write_all(&batch_bytes)?;
+                offset += batch_bytes.len() as u64;
+                next_idx = batch_range(next_batch).1;
+                next_batch += 1;
+                
+                // Update progress and flush less frequently
+                if next_batch.is_multiple_of(10) {
+                    writing_progress.set_position(next_idx - range_start);
+                    writer.flush()?;
+                    writer.get_ref().sync_data()?;
+                    save_checkpoint(next_idx, offset)?;
+                }
             }
         }
         writer.flush()?;
+        writer.get_ref().sync_data()?;
+        save_checkpoint(next_idx, offset)?;
+        writing_progress.set_position(next_idx - range_start);
         writing_progress.finish_with_message("All mazes written!");
         Ok(())
     });
     
     
     // Parallel generation
-    let seed = args.seed;
-    let rows = args.rows;
-    let cols = args.cols;
+    // Generate and solve a single instance, seeded by the candidate it was
+    // selected from in a stratified run
+    let make_result = |instance_id: u64, selected: Option<&stratify::Selection>| -> Result<MazeResult, String> {
+        let candidate = selected.map(|selected| selected.candidate(instance_id));
+        let (maze, solution) = solve_candidate(candidate.unwrap_or(instance_id))?;
+        let plan_optimal = solvers::plan_optimal(solver, &maze, &solution);
+        
+        Ok(MazeResult {
+            instance_id,
+            maze,
+            solution,
+            generator,
+            solver,
+            seed,
+            seed_scheme,
+            placement: placement.policy,
+            candidate,
+            plan_optimal,
+            trace_donor: None,
+            trace_transform,
+        })
+    };
     
+    // Build one batch and serialize it
+    let selection_totals = (AtomicU64::new(0), AtomicU64::new(0));
+    let make_batch = |batch_idx: u64| -> Result<Vec<u8>, String> {
+        let (batch_start, batch_end) = batch_range(batch_idx);
+        
+        // A stratified batch first picks the candidates of the blocks it
+        // writes (whole blocks, which also hold every donor of a swapped trace)
+        let selected = match &strata {
+            Some(strata) => {
+                let selected = strata.select(seed, batch_start, batch_end, |candidate| {
+                    solve_candidate(candidate).map(|(_, solution)| strata.bucket(&solution))
+                })?;
+                selection_totals.0.fetch_add(selected.accepted, Ordering::Relaxed);
+                selection_totals.1.fetch_add(selected.tried, Ordering::Relaxed);
+                Some(selected)
+            }
+            None => None,
+        };
+        
+        let mut batch_results: Vec<MazeResult> = (batch_start..batch_end)
+            .map(|instance_id| make_result(instance_id, selected.as_ref()))
+            .collect::<Result<_, _>>()?;
+        
+        // Pair each maze with another instance's trace
+        if trace_mode == TraceMode::Swapped {
+            traces::swap_traces(&mut batch_results, seed, count, |donor| make_result(donor, selected.as_ref()))?;
+        }
+        
+        // Corrupt traces for ablations
+        if let Some(transform) = &trace_transform {
+            for result in &mut batch_results {
+                transform.apply(result);
+            }
+        }
+        
+        Ok(process_batch(&batch_results))
+    };
     
     // Process mazes in batches
-    (0..count)
-        .step_by(BATCH_SIZE)
-        .collect::<Vec<_>>()
+    // Workers claim batches in index order, which keeps the writer's
+    // reorder buffer down to roughly one batch per thread.
+    let next_batch = AtomicU64::new(0);
+    let generation: Result<(), String> = (0..rayon::current_num_threads())
         .into_par_iter()
-        .for_each_with(tx, |tx, batch_start| {
-            let batch_end = (batch_start + BATCH_SIZE as u64).min(count);
-            let mut batch_results: Vec<MazeResult> = Vec::with_capacity(BATCH_SIZE);
-            
-            for instance_id in batch_start..batch_end {
-                // Create instance PRNG
-                let mut rng = create_instance_prng(seed, generator, solver, instance_id);
-                
-                // Generate maze
-                let maze = generators::generate_maze(generator, &mut rng, rows, cols, &generator_params);
-                
-                // Solve maze
-                let solution = solvers::solve_maze(solver, &maze);
-                
-                // Create result
-                let result = MazeResult {
-                    instance_id,
-                    maze,
-                    solution,
-                    generator,
-                    solver,
-                    seed,
-                };
-                
-                // Add to batch
-                batch_results.push(result);
+        .try_for_each_with(tx, |tx, _| loop {
+            let batch_idx = next_batch.fetch_add(1, Ordering::Relaxed);
+            if batch_idx >= num_batches {
+                return Ok(());
             }
             
-            // Process and send entire batch as bytes
-            let batch_bytes = process_batch(&batch_results);
-            tx.send(batch_bytes).unwrap();
+            // On failure, stop the other workers from claiming more batches
+            let batch_bytes = make_batch(batch_idx).inspect_err(|_| {
+                next_batch.store(num_batches, Ordering::Relaxed);
+            })?;
+            tx.send((batch_idx, batch_bytes)).unwrap();
         });
     
     
     // Channel will be closed when all senders are dropped
     // Wait for writer to finish
     writer_handle.join().unwrap()?;
+    generation?;
     
     let elapsed = start_time.elapsed();
-    let rate = count as f64 / elapsed.as_secs_f64();
+    let generated = range_end - resume_start;
+    let rate = generated as f64 / elapsed.as_secs_f64();
     
     println!("\nCompleted in {:.2}s", elapsed.as_secs_f64());
-    println!("Generated {} mazes at {:.2} mazes/second", count, rate);
+    println!("Generated {} mazes at {:.2} mazes/second", generated, rate);
+    if strata.is_some() {
+        let (accepted, tried) = (selection_totals.0.into_inner(), selection_totals.1.into_inner());
+        println!(
+            "Selected {} of {} candidates ({:.2}% accepted)",
+            accepted, tried, 100.0 * accepted as f64 / tried.max(1) as f64
+        );
+    }
     
     Ok(())
 }
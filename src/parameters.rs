@@ -1,10 +1,52 @@
 use std::collections::HashMap;
-use crate::GeneratorType;
+use std::fmt;
+use clap::ValueEnum;
+use crate::{GeneratorType, PlacementType, TransformType};
 
-/// Parameters for generators
+/// A typed parameter value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParamValue {
+    Float(f64),
+    Int(i64),
+    Bool(bool),
+    Choice(&'static str),
+}
+
+impl fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamValue::Float(v) => write!(f, "{}", v),
+            ParamValue::Int(v) => write!(f, "{}", v),
+            ParamValue::Bool(v) => write!(f, "{}", v),
+            ParamValue::Choice(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// Type of values a parameter accepts
+#[derive(Debug, Clone, Copy)]
+pub enum ParamKind {
+    Float,
+    Int,
+    Bool,
+    Choice(&'static [&'static str]),
+}
+
+impl fmt::Display for ParamKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamKind::Float => write!(f, "number"),
+            ParamKind::Int => write!(f, "integer"),
+            ParamKind::Bool => write!(f, "true/false"),
+            ParamKind::Choice(choices) => write!(f, "one of {}", choices.join(", ")),
+        }
+    }
+}
+
+/// Parameters for generators and other pipeline stages, validated against a `ParamInfo` schema
 #[derive(Debug, Clone)]
 pub struct GeneratorParams {
-    params: HashMap<String, f64>,
+    params: HashMap<String, ParamValue>,
 }
 
 impl GeneratorParams {
@@ -14,45 +56,208 @@ impl GeneratorParams {
         }
     }
     
+    /// Whether the key was given explicitly rather than left at its default
+    pub fn contains(&self, key: &str) -> bool {
+        self.params.contains_key(key)
+    }
+    
+    /// Numeric value (integers and bools convert to f64)
     pub fn get(&self, key: &str, default: f64) -> f64 {
-        self.params.get(key).copied().unwrap_or(default)
+        match self.params.get(key) {
+            Some(ParamValue::Float(v)) => *v,
+            Some(ParamValue::Int(v)) => *v as f64,
+            Some(ParamValue::Bool(v)) => *v as u8 as f64,
+            Some(ParamValue::Choice(_)) | None => default,
+        }
+    }
+    
+    pub fn get_int(&self, key: &str, default: i64) -> i64 {
+        match self.params.get(key) {
+            Some(ParamValue::Int(v)) => *v,
+            _ => default,
+        }
+    }
+    
+    pub fn get_bool(&self, key: &str, default: bool) -> bool {
+        match self.params.get(key) {
+            Some(ParamValue::Bool(v)) => *v,
+            _ => default,
+        }
+    }
+    
+    pub fn get_choice(&self, key: &str, default: &'static str) -> &'static str {
+        match self.params.get(key) {
+            Some(ParamValue::Choice(v)) => v,
+            _ => default,
+        }
     }
     
-    pub fn from_vec(pairs: Vec<(String, String)>) -> Result<Self, String> {
+    /// Parse key=value pairs against a schema.
+    /// Rejects unknown keys (suggesting the closest name), values of the
+    /// wrong type, out-of-range values and repeated keys.
+    /// `owner` names what the parameters belong to in error messages.
+    pub fn parse(schema: &[ParamInfo], pairs: Vec<(String, String)>, owner: &str) -> Result<Self, String> {
         let mut params = HashMap::new();
         for (key, value) in pairs {
-            let val = value.parse::<f64>()
-                .map_err(|_| format!("Invalid value for parameter '{}': '{}' (must be a number)", key, value))?;
-            params.insert(key, val);
+            let Some(info) = schema.iter().find(|info| info.name == key) else {
+                let names: Vec<&str> = schema.iter().map(|info| info.name).collect();
+                return Err(match closest_match(&key, &names) {
+                    Some(suggestion) => format!(
+                        "Unknown parameter '{}' for {}. Did you mean '{}'?",
+                        key, owner, suggestion
+                    ),
+                    None if names.is_empty() => format!(
+                        "Unknown parameter '{}': {} has no parameters",
+                        key, owner
+                    ),
+                    None => format!(
+                        "Unknown parameter '{}' for {} (valid parameters: {})",
+                        key, owner, names.join(", ")
+                    ),
+                });
+            };
+            
+            let parsed = info.parse_value(&value)?;
+            if params.insert(key.clone(), parsed).is_some() {
+                return Err(format!("Parameter '{}' given more than once", key));
+            }
         }
         Ok(Self { params })
     }
 }
 
+/// Sorted `key=value` list of the parsed values, so settings compare equal
+/// however their values were written (`0.5` and `.5`)
+impl fmt::Display for GeneratorParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut keys: Vec<&String> = self.params.keys().collect();
+        keys.sort();
+        let pairs: Vec<String> = keys.iter().map(|key| format!("{}={}", key, self.params[*key])).collect();
+        write!(f, "[{}]", pairs.join(" "))
+    }
+}
+
 impl Default for GeneratorParams {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// Parameter information for help display
+/// Parameter information for validation and help display
 #[derive(Debug, Clone)]
 pub struct ParamInfo {
     pub name: &'static str,
     pub description: &'static str,
-    pub default: f64,
+    pub kind: ParamKind,
+    pub default: ParamValue,
     pub min: Option<f64>,
     pub max: Option<f64>,
 }
 
-/// Get parameter descriptions for a generator
+impl ParamInfo {
+    /// Parse and range-check a raw value for this parameter
+    fn parse_value(&self, value: &str) -> Result<ParamValue, String> {
+        let invalid = || format!(
+            "Invalid value for parameter '{}': '{}' (must be {})",
+            self.name, value, self.kind
+        );
+        let parsed = match self.kind {
+            ParamKind::Float => ParamValue::Float(
+                value.parse::<f64>().ok().filter(|v| v.is_finite()).ok_or_else(invalid)?,
+            ),
+            ParamKind::Int => ParamValue::Int(value.parse::<i64>().map_err(|_| invalid())?),
+            ParamKind::Bool => ParamValue::Bool(match value {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                _ => return Err(invalid()),
+            }),
+            ParamKind::Choice(choices) => match choices.iter().find(|&&c| c == value) {
+                Some(choice) => ParamValue::Choice(choice),
+                None => {
+                    return Err(match closest_match(value, choices) {
+                        Some(suggestion) => format!("{}. Did you mean '{}'?", invalid(), suggestion),
+                        None => invalid(),
+                    })
+                }
+            },
+        };
+        
+        let numeric = match parsed {
+            ParamValue::Float(v) => Some(v),
+            ParamValue::Int(v) => Some(v as f64),
+            _ => None,
+        };
+        if let Some(v) = numeric {
+            if self.min.is_some_and(|min| v < min) || self.max.is_some_and(|max| v > max) {
+                return Err(format!(
+                    "Value {} for parameter '{}' is out of range ({})",
+                    value, self.name, self.range_text()
+                ));
+            }
+        }
+        Ok(parsed)
+    }
+    
+    fn range_text(&self) -> String {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => format!("{} to {}", min, max),
+            (Some(min), None) => format!("at least {}", min),
+            (None, Some(max)) => format!("at most {}", max),
+            (None, None) => "any value".to_string(),
+        }
+    }
+}
+
+/// Closest candidate within a small edit distance, for "did you mean" hints
+fn closest_match<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|&candidate| (edit_distance(input, candidate), candidate))
+        .filter(|&(distance, candidate)| distance <= (candidate.len() / 3).max(2))
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + (ca != cb) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// CLI name of a generator, as accepted by `--generator`
+pub fn generator_name(generator: GeneratorType) -> String {
+    generator.to_possible_value().unwrap().get_name().to_string()
+}
+
+/// CLI name of a trace transform, as accepted by `--trace-transform`
+pub fn transform_name(transform: TransformType) -> String {
+    transform.to_possible_value().unwrap().get_name().to_string()
+}
+
+/// CLI name of a placement policy, as accepted by `--placement`
+pub fn placement_name(placement: PlacementType) -> String {
+    placement.to_possible_value().unwrap().get_name().to_string()
+}
+
+/// Get parameter descriptions for a generator, including the common post-processing ones
 pub fn get_generator_params(generator: GeneratorType) -> Vec<ParamInfo> {
-    match generator {
+    let mut params = match generator {
         GeneratorType::DrunkardsWalk => vec![
             ParamInfo {
                 name: "coverage",
                 description: "Fraction of cells to carve (0.0 to 1.0)",
-                default: 0.5,
+                kind: ParamKind::Float,
+                default: ParamValue::Float(0.5),
                 min: Some(0.01),
                 max: Some(1.0),
             },
@@ -60,21 +265,295 @@ pub fn get_generator_params(generator: GeneratorType) -> Vec<ParamInfo> {
         GeneratorType::Searchformer => vec![
             ParamInfo {
                 name: "min_path_length",
-                description: "Minimum required path length from start to goal",
-                default: 10.0,
-                min: Some(1.0),
+                description: "Minimum required path length from start to goal, in cells (0 = max(rows, cols))",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(0),
+                min: Some(0.0),
                 max: None,
             },
+            ParamInfo {
+                name: "min_wall_density",
+                description: "Lowest fraction of cells sampled as walls",
+                kind: ParamKind::Float,
+                default: ParamValue::Float(0.3),
+                min: Some(0.0),
+                max: Some(1.0),
+            },
+            ParamInfo {
+                name: "max_wall_density",
+                description: "Highest fraction of cells sampled as walls",
+                kind: ParamKind::Float,
+                default: ParamValue::Float(0.5),
+                min: Some(0.0),
+                max: Some(1.0),
+            },
             ParamInfo {
                 name: "wall_sample_rate",
-                description: "Fraction of walls to randomly remove",
-                default: 0.3,
+                description: "Fraction of sampled walls to randomly remove again",
+                kind: ParamKind::Float,
+                default: ParamValue::Float(0.0),
+                min: Some(0.0),
+                max: Some(1.0),
+            },
+            ParamInfo {
+                name: "max_attempts",
+                description: "Number of mazes to sample before giving up with an error",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(1000),
+                min: Some(1.0),
+                max: None,
+            },
+        ],
+        GeneratorType::GrowingTree => vec![
+            ParamInfo {
+                name: "policy",
+                description: "Which active cell to grow from: newest (DFS-like), random (Prim-like), oldest, or mix",
+                kind: ParamKind::Choice(&["newest", "random", "oldest", "mix"]),
+                default: ParamValue::Choice("newest"),
+                min: None,
+                max: None,
+            },
+            ParamInfo {
+                name: "newest_prob",
+                description: "With policy=mix, probability of growing from the newest cell instead of a random one",
+                kind: ParamKind::Float,
+                default: ParamValue::Float(0.75),
+                min: Some(0.0),
+                max: Some(1.0),
+            },
+        ],
+        GeneratorType::RecursiveDivision => vec![
+            ParamInfo {
+                name: "horizontal_bias",
+                description: "Probability of splitting a chamber with a horizontal wall when both orientations fit",
+                kind: ParamKind::Float,
+                default: ParamValue::Float(0.5),
+                min: Some(0.0),
+                max: Some(1.0),
+            },
+        ],
+        GeneratorType::Eller => vec![
+            ParamInfo {
+                name: "merge_prob",
+                description: "Probability of joining horizontally adjacent rooms in different sets (higher gives longer horizontal corridors)",
+                kind: ParamKind::Float,
+                default: ParamValue::Float(0.5),
                 min: Some(0.0),
                 max: Some(1.0),
             },
         ],
-        // Generators without parameters
-        GeneratorType::Wilson | GeneratorType::Dfs | GeneratorType::Kruskal => vec![],
+        GeneratorType::BinaryTree | GeneratorType::Sidewinder => vec![
+            ParamInfo {
+                name: "bias",
+                description: "Corner the passages lean toward; each room opens toward one of its two sides",
+                kind: ParamKind::Choice(&["northwest", "northeast", "southwest", "southeast"]),
+                default: ParamValue::Choice("northwest"),
+                min: None,
+                max: None,
+            },
+        ],
+        GeneratorType::CellularAutomata => vec![
+            ParamInfo {
+                name: "fill_prob",
+                description: "Probability of a cell starting as wall",
+                kind: ParamKind::Float,
+                default: ParamValue::Float(0.45),
+                min: Some(0.0),
+                max: Some(1.0),
+            },
+            ParamInfo {
+                name: "iterations",
+                description: "Number of smoothing passes",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(4),
+                min: Some(0.0),
+                max: None,
+            },
+            ParamInfo {
+                name: "birth_limit",
+                description: "Floor cells with at least this many wall neighbors (of 8) become wall",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(5),
+                min: Some(0.0),
+                max: Some(9.0),
+            },
+            ParamInfo {
+                name: "survival_limit",
+                description: "Wall cells with at least this many wall neighbors (of 8) stay wall",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(4),
+                min: Some(0.0),
+                max: Some(9.0),
+            },
+            ParamInfo {
+                name: "keep_largest",
+                description: "Fill in every cave but the largest (false keeps them all, so uniform placement may pick an unreachable goal)",
+                kind: ParamKind::Bool,
+                default: ParamValue::Bool(true),
+                min: None,
+                max: None,
+            },
+            ParamInfo {
+                name: "max_attempts",
+                description: "Number of caves to sample before giving up with an error",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(100),
+                min: Some(1.0),
+                max: None,
+            },
+        ],
+        GeneratorType::BspDungeon => vec![
+            ParamInfo {
+                name: "min_room_size",
+                description: "Minimum room width and height",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(3),
+                min: Some(1.0),
+                max: None,
+            },
+            ParamInfo {
+                name: "max_room_size",
+                description: "Maximum room width and height",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(8),
+                min: Some(1.0),
+                max: None,
+            },
+            ParamInfo {
+                name: "split_depth",
+                description: "Number of BSP splits from the whole grid to a leaf (up to 2^depth rooms)",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(4),
+                min: Some(0.0),
+                max: Some(16.0),
+            },
+        ],
+        // Generators without their own parameters
+        GeneratorType::Wilson
+        | GeneratorType::Dfs
+        | GeneratorType::Kruskal
+        | GeneratorType::Prim
+        | GeneratorType::AldousBroder
+        | GeneratorType::HuntAndKill => vec![],
+    };
+    
+    // Applied by `generators::generate_maze` to every generator's output
+    params.push(ParamInfo {
+        name: "braid",
+        description: "Fraction of dead ends opened into a neighboring passage, adding loops (0 keeps the maze as generated)",
+        kind: ParamKind::Float,
+        default: ParamValue::Float(0.0),
+        min: Some(0.0),
+        max: Some(1.0),
+    });
+    params
+}
+
+/// Default `rate` of a trace transform
+pub fn default_transform_rate(transform: TransformType) -> f64 {
+    match transform {
+        TransformType::Shuffle => 1.0,
+        TransformType::Drop => 0.1,
+        TransformType::Truncate => 0.5,
+        TransformType::Perturb => 0.1,
+        TransformType::Inject => 0.1,
+        TransformType::None => 0.0,
+    }
+}
+
+/// Default `magnitude` of the perturb transform
+pub const DEFAULT_PERTURB_MAGNITUDE: i64 = 2;
+
+/// Get parameter descriptions for a trace transform
+pub fn get_transform_params(transform: TransformType) -> Vec<ParamInfo> {
+    let rate = |description| ParamInfo {
+        name: "rate",
+        description,
+        kind: ParamKind::Float,
+        default: ParamValue::Float(default_transform_rate(transform)),
+        min: Some(0.0),
+        max: Some(1.0),
+    };
+    match transform {
+        TransformType::Shuffle => vec![
+            rate("Fraction of events whose positions are shuffled among each other"),
+        ],
+        TransformType::Drop => vec![
+            rate("Fraction of events removed at random"),
+        ],
+        TransformType::Truncate => vec![
+            rate("Fraction of events cut from the end of the trace"),
+        ],
+        TransformType::Perturb => vec![
+            rate("Fraction of events whose g or h value (BFS: depth) is changed"),
+            ParamInfo {
+                name: "magnitude",
+                description: "Maximum absolute change applied to a perturbed value",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(DEFAULT_PERTURB_MAGNITUDE),
+                min: Some(1.0),
+                max: None,
+            },
+        ],
+        TransformType::Inject => vec![
+            rate("Number of random events inserted, as a fraction of trace length"),
+        ],
+        TransformType::None => vec![],
+    }
+}
+
+/// Get parameter descriptions for a start/goal placement policy
+pub fn get_placement_params(placement: PlacementType) -> Vec<ParamInfo> {
+    let max_attempts = ParamInfo {
+        name: "max_attempts",
+        description: "Number of random starts to try before giving up with an error",
+        kind: ParamKind::Int,
+        default: ParamValue::Int(100),
+        min: Some(1.0),
+        max: None,
+    };
+    match placement {
+        PlacementType::Band => vec![
+            ParamInfo {
+                name: "min_distance",
+                description: "Minimum shortest-path distance (in moves) from start to goal",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(1),
+                min: Some(1.0),
+                max: None,
+            },
+            ParamInfo {
+                name: "max_distance",
+                description: "Maximum shortest-path distance (in moves) from start to goal (0 for no limit)",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(0),
+                min: Some(0.0),
+                max: None,
+            },
+            max_attempts,
+        ],
+        PlacementType::ExactDistance => vec![
+            ParamInfo {
+                name: "distance",
+                description: "Shortest-path distance (in moves) from start to goal",
+                kind: ParamKind::Int,
+                default: ParamValue::Int(10),
+                min: Some(1.0),
+                max: None,
+            },
+            max_attempts,
+        ],
+        PlacementType::Opposite => vec![
+            ParamInfo {
+                name: "anchor",
+                description: "Place start and goal at opposite corners or at opposite sides",
+                kind: ParamKind::Choice(&["corners", "sides"]),
+                default: ParamValue::Choice("corners"),
+                min: None,
+                max: None,
+            },
+        ],
+        PlacementType::Uniform | PlacementType::Diameter | PlacementType::DeadEnds => vec![],
     }
 }
 
@@ -83,16 +562,17 @@ pub fn print_param_help(generator: GeneratorType) {
     let params = get_generator_params(generator);
     
     if params.is_empty() {
-        println!("Generator '{}' has no configurable parameters.", format!("{:?}", generator).to_lowercase());
+        println!("Generator '{}' has no configurable parameters.", generator_name(generator));
         return;
     }
     
-    println!("Parameters for '{}' generator:", format!("{:?}", generator).to_lowercase());
+    println!("Parameters for '{}' generator:", generator_name(generator));
     println!();
     
     for param in params {
         println!("  --param {}=<value>", param.name);
         println!("    {}", param.description);
+        println!("    Type: {}", param.kind);
         println!("    Default: {}", param.default);
         if let (Some(min), Some(max)) = (param.min, param.max) {
             println!("    Range: {} to {}", min, max);
@@ -115,11 +595,21 @@ pub fn print_all_params_help() {
         GeneratorType::Wilson,
         GeneratorType::Dfs,
         GeneratorType::Kruskal,
+        GeneratorType::Prim,
+        GeneratorType::GrowingTree,
+        GeneratorType::RecursiveDivision,
+        GeneratorType::Eller,
+        GeneratorType::AldousBroder,
+        GeneratorType::BinaryTree,
+        GeneratorType::Sidewinder,
+        GeneratorType::HuntAndKill,
+        GeneratorType::CellularAutomata,
+        GeneratorType::BspDungeon,
         GeneratorType::DrunkardsWalk,
         GeneratorType::Searchformer,
     ] {
         let params = get_generator_params(generator);
-        let gen_name = format!("{:?}", generator).to_lowercase();
+        let gen_name = generator_name(generator);
         
         if params.is_empty() {
             println!("{}: No parameters", gen_name);
@@ -131,4 +621,86 @@ pub fn print_all_params_help() {
         }
         println!();
     }
-}
\ No newline at end of file
+    
+    println!("Trace Transform Parameters (--trace-param):");
+    println!("===========================================");
+    println!();
+    
+    for transform in [
+        TransformType::Shuffle,
+        TransformType::Drop,
+        TransformType::Truncate,
+        TransformType::Perturb,
+        TransformType::Inject,
+    ] {
+        let params = get_transform_params(transform);
+        println!("{}: {} parameter(s)", transform_name(transform), params.len());
+        for param in params {
+            println!("  - {}: {} (default: {})", param.name, param.description, param.default);
+        }
+        println!();
+    }
+    
+    println!("Placement Parameters (--placement-param):");
+    println!("=========================================");
+    println!();
+    
+    for placement in [PlacementType::Band, PlacementType::ExactDistance, PlacementType::Opposite] {
+        let params = get_placement_params(placement);
+        println!("{}: {} parameter(s)", placement_name(placement), params.len());
+        for param in params {
+            println!("  - {}: {} (default: {})", param.name, param.description, param.default);
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn info(name: &'static str, kind: ParamKind, min: Option<f64>, max: Option<f64>) -> ParamInfo {
+        ParamInfo { name, description: "", kind, default: ParamValue::Int(0), min, max }
+    }
+    
+    fn parse(schema: &[ParamInfo], pairs: &[(&str, &str)]) -> Result<GeneratorParams, String> {
+        let pairs = pairs.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect();
+        GeneratorParams::parse(schema, pairs, "test")
+    }
+    
+    #[test]
+    fn parses_bools() {
+        let schema = [info("flag", ParamKind::Bool, None, None)];
+        for (value, expected) in [("true", true), ("1", true), ("false", false), ("0", false)] {
+            let params = parse(&schema, &[("flag", value)]).unwrap();
+            assert_eq!(params.get_bool("flag", !expected), expected, "{}", value);
+        }
+        assert!(parse(&schema, &[("flag", "yes")]).is_err());
+        assert!(parse(&schema, &[]).unwrap().get_bool("flag", true));
+    }
+    
+    #[test]
+    fn parses_typed_values() {
+        let schema = [
+            info("rate", ParamKind::Float, Some(0.0), Some(1.0)),
+            info("count", ParamKind::Int, Some(1.0), None),
+            info("policy", ParamKind::Choice(&["newest", "random"]), None, None),
+        ];
+        let params = parse(&schema, &[("rate", ".5"), ("count", "3"), ("policy", "random")]).unwrap();
+        assert_eq!(params.get("rate", 0.0), 0.5);
+        assert_eq!(params.get_int("count", 0), 3);
+        assert_eq!(params.get_choice("policy", "newest"), "random");
+        
+        assert!(parse(&schema, &[("count", "1.5")]).is_err());
+        assert!(parse(&schema, &[("rate", "NaN")]).is_err());
+        assert!(parse(&schema, &[("policy", "oldest")]).is_err());
+    }
+    
+    #[test]
+    fn rejects_out_of_range_repeated_and_unknown_keys() {
+        let schema = [info("coverage", ParamKind::Float, Some(0.01), Some(1.0))];
+        assert!(parse(&schema, &[("coverage", "1.5")]).unwrap_err().contains("out of range"));
+        assert!(parse(&schema, &[("coverage", "0.5"), ("coverage", "0.6")]).unwrap_err().contains("more than once"));
+        assert!(parse(&schema, &[("coverge", "0.7")]).unwrap_err().contains("Did you mean 'coverage'?"));
+    }
+}
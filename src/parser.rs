@@ -0,0 +1,103 @@
+use crate::types::{Maze, ReasoningEvent};
+
+/// Sections recovered from a `query .. reasoning .. solution .. end` text.
+/// Any section may be missing, so model outputs holding only a trace and
+/// a plan parse as well as full dataset lines.
+#[derive(Clone, Debug, Default)]
+pub struct ParsedText {
+    pub start: Option<(usize, usize)>,
+    pub goal: Option<(usize, usize)>,
+    pub walls: Vec<(usize, usize)>,
+    pub reasoning: Vec<ReasoningEvent>,
+    pub plan: Vec<(usize, usize)>,
+}
+
+/// Parse the token stream written by `serializer::write_maze_json`
+pub fn parse_text(text: &str) -> Result<ParsedText, String> {
+    let mut parsed = ParsedText::default();
+    let mut tokens = text.split_ascii_whitespace();
+    
+    while let Some(token) = tokens.next() {
+        match token {
+            "query" | "reasoning" | "solution" => {}
+            "end" => break,
+            "start" => parsed.start = Some(parse_coords(&mut tokens, token)?),
+            "goal" => parsed.goal = Some(parse_coords(&mut tokens, token)?),
+            "wall" => parsed.walls.push(parse_coords(&mut tokens, token)?),
+            "plan" => parsed.plan.push(parse_coords(&mut tokens, token)?),
+            "close" | "create" => {
+                let (x, y) = parse_coords(&mut tokens, token)?;
+                let g = parse_cost(&mut tokens, token)?;
+                let h = parse_cost(&mut tokens, token)?;
+                let (x, y) = (to_u32(x)?, to_u32(y)?);
+                parsed.reasoning.push(if token == "close" {
+                    ReasoningEvent::Close { x, y, g, h }
+                } else {
+                    ReasoningEvent::Create { x, y, g, h }
+                });
+            }
+            "dequeue" | "enqueue" => {
+                let (x, y) = parse_coords(&mut tokens, token)?;
+                let depth = parse_cost(&mut tokens, token)?;
+                let (x, y) = (to_u32(x)?, to_u32(y)?);
+                parsed.reasoning.push(if token == "dequeue" {
+                    ReasoningEvent::Dequeue { x, y, depth }
+                } else {
+                    ReasoningEvent::Enqueue { x, y, depth }
+                });
+            }
+            other => return Err(format!("unexpected token '{}'", other)),
+        }
+    }
+    
+    Ok(parsed)
+}
+
+/// Rebuild the maze grid: every listed wall is a wall, every other cell is floor
+pub fn rebuild_maze(parsed: &ParsedText, rows: usize, cols: usize) -> Result<Maze, String> {
+    let mut maze = Maze::new(rows, cols);
+    for y in 0..rows {
+        for x in 0..cols {
+            maze.set_cell(x, y, true);
+        }
+    }
+    for &(x, y) in &parsed.walls {
+        if x >= cols || y >= rows {
+            return Err(format!("wall {} {} outside {}x{} maze", x, y, rows, cols));
+        }
+        maze.set_cell(x, y, false);
+    }
+    
+    maze.start = parsed.start.ok_or("missing start")?;
+    maze.goal = parsed.goal.ok_or("missing goal")?;
+    for (name, (x, y)) in [("start", maze.start), ("goal", maze.goal)] {
+        if !maze.get_cell(x, y) {
+            return Err(format!("{} {} {} is not a floor cell", name, x, y));
+        }
+    }
+    Ok(maze)
+}
+
+fn parse_coords<'a>(
+    tokens: &mut impl Iterator<Item = &'a str>,
+    keyword: &str,
+) -> Result<(usize, usize), String> {
+    let mut next = || -> Result<usize, String> {
+        let token = tokens.next().ok_or_else(|| format!("'{}' missing coordinate", keyword))?;
+        token.parse().map_err(|_| format!("'{}' has invalid coordinate '{}'", keyword, token))
+    };
+    Ok((next()?, next()?))
+}
+
+/// Costs and depths are written as `c<value>`
+fn parse_cost<'a>(tokens: &mut impl Iterator<Item = &'a str>, keyword: &str) -> Result<u32, String> {
+    let token = tokens.next().ok_or_else(|| format!("'{}' missing cost", keyword))?;
+    token
+        .strip_prefix('c')
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("'{}' has invalid cost '{}'", keyword, token))
+}
+
+fn to_u32(value: usize) -> Result<u32, String> {
+    u32::try_from(value).map_err(|_| format!("coordinate {} out of range", value))
+}
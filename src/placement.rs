@@ -0,0 +1,363 @@
+use crate::parameters::GeneratorParams;
+use crate::types::Maze;
+use crate::PlacementType;
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use std::collections::VecDeque;
+
+/// Start and goal cells
+type Endpoints = ((usize, usize), (usize, usize));
+
+const DIRECTIONS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Start/goal placement policy with its parameters
+#[derive(Clone, Copy, Debug)]
+pub struct Placement {
+    pub policy: PlacementType,
+    /// Shortest-path distance range (in moves) for band and exact-distance
+    min_distance: usize,
+    max_distance: usize,
+    /// Start cells tried by band and exact-distance before giving up
+    max_attempts: usize,
+    /// What opposite placement anchors to: "corners" or "sides"
+    anchor: &'static str,
+}
+
+impl Placement {
+    pub fn new(policy: PlacementType, params: &GeneratorParams) -> Result<Self, String> {
+        let (min_distance, max_distance) = match policy {
+            PlacementType::Band => {
+                let min = params.get_int("min_distance", 1) as usize;
+                // 0 leaves the band open-ended
+                let max = match params.get_int("max_distance", 0) as usize {
+                    0 => usize::MAX,
+                    n => n,
+                };
+                if min > max {
+                    return Err(format!(
+                        "placement band: min_distance ({}) is greater than max_distance ({})",
+                        min, max
+                    ));
+                }
+                (min, max)
+            }
+            PlacementType::ExactDistance => {
+                let k = params.get_int("distance", 10) as usize;
+                (k, k)
+            }
+            _ => (0, usize::MAX),
+        };
+        
+        Ok(Placement {
+            policy,
+            min_distance,
+            max_distance,
+            max_attempts: params.get_int("max_attempts", 100) as usize,
+            anchor: params.get_choice("anchor", "corners"),
+        })
+    }
+    
+    /// Choose `maze.start` and `maze.goal` according to the policy.
+    ///
+    /// Uniform keeps the original draws (any two floor cells, reachable or
+    /// not) so existing datasets are unchanged. Every other policy picks both
+    /// cells from the largest connected floor region, so the goal is always
+    /// reachable.
+    pub fn place(&self, rng: &mut Xoshiro256PlusPlus, maze: &mut Maze) -> Result<(), String> {
+        if self.policy == PlacementType::Uniform {
+            let floors = floor_cells(maze);
+            if floors.len() < 2 {
+                return Err(format!("placement: maze has {} floor cell(s), need 2", floors.len()));
+            }
+            let (start, goal) = distinct_pair(rng, &floors);
+            maze.start = start;
+            maze.goal = goal;
+            return Ok(());
+        }
+        
+        let cells = largest_component(maze);
+        if cells.len() < 2 {
+            return Err(format!("placement: largest floor region has {} cell(s), need 2", cells.len()));
+        }
+        
+        let (start, goal) = match self.policy {
+            PlacementType::Uniform => unreachable!(),
+            PlacementType::Diameter => {
+                // Double BFS: the farthest cell from anywhere is a diameter endpoint
+                // (exact on perfect mazes, a close lower bound when there are loops)
+                let probe = cells[rng.gen_range(0..cells.len())];
+                let first = farthest(rng, maze, &cells, probe);
+                let second = farthest(rng, maze, &cells, first);
+                if rng.gen::<f64>() < 0.5 { (first, second) } else { (second, first) }
+            }
+            PlacementType::Band | PlacementType::ExactDistance => self.place_in_band(rng, maze, &cells)?,
+            PlacementType::DeadEnds => {
+                let dead_ends: Vec<(usize, usize)> = cells
+                    .iter()
+                    .copied()
+                    .filter(|&(x, y)| floor_neighbors(maze, x, y).count() == 1)
+                    .collect();
+                if dead_ends.len() < 2 {
+                    return Err(format!("placement dead-ends: maze has {} dead end(s), need 2", dead_ends.len()));
+                }
+                distinct_pair(rng, &dead_ends)
+            }
+            PlacementType::Opposite => self.place_opposite(rng, maze, &cells),
+        };
+        
+        maze.start = start;
+        maze.goal = goal;
+        Ok(())
+    }
+    
+    /// Random start, then a random goal whose distance falls in the band
+    fn place_in_band(
+        &self,
+        rng: &mut Xoshiro256PlusPlus,
+        maze: &Maze,
+        cells: &[(usize, usize)],
+    ) -> Result<Endpoints, String> {
+        let mut candidates = Vec::new();
+        for _ in 0..self.max_attempts {
+            let start = cells[rng.gen_range(0..cells.len())];
+            let dist = distances(maze, start);
+            candidates.clear();
+            candidates.extend(cells.iter().copied().filter(|&(x, y)| {
+                let d = dist[y * maze.cols + x];
+                d > 0 && d >= self.min_distance && d <= self.max_distance
+            }));
+            if !candidates.is_empty() {
+                return Ok((start, candidates[rng.gen_range(0..candidates.len())]));
+            }
+        }
+        
+        let band = if self.min_distance == self.max_distance {
+            format!("exactly {}", self.min_distance)
+        } else if self.max_distance == usize::MAX {
+            format!("at least {}", self.min_distance)
+        } else {
+            format!("{} to {}", self.min_distance, self.max_distance)
+        };
+        Err(format!(
+            "placement: no goal at distance {} from {} random starts in a {}x{} maze \
+             (widen the distance range or raise max_attempts)",
+            band, self.max_attempts, maze.rows, maze.cols
+        ))
+    }
+    
+    /// Start nearest one corner (or side), goal nearest the opposite one
+    fn place_opposite(
+        &self,
+        rng: &mut Xoshiro256PlusPlus,
+        maze: &Maze,
+        cells: &[(usize, usize)],
+    ) -> Endpoints {
+        let (w, h) = (maze.cols - 1, maze.rows - 1);
+        
+        // Which coordinates the anchor constrains, and whether it sits at their far end
+        let choice = rng.gen::<f64>() < 0.5;
+        let flip = rng.gen::<f64>() < 0.5;
+        let (use_x, use_y, far_x, far_y) = if self.anchor == "sides" {
+            (choice, !choice, flip, flip)
+        } else {
+            (true, true, flip, flip ^ choice)
+        };
+        
+        // Manhattan distance to the start anchor; on a grid the cells farthest
+        // from it are the ones closest to the opposite anchor
+        let key = |(x, y): (usize, usize)| {
+            let dx = if far_x { w - x } else { x };
+            let dy = if far_y { h - y } else { y };
+            (if use_x { dx } else { 0 }) + (if use_y { dy } else { 0 })
+        };
+        
+        let start = pick_extreme(rng, cells, key);
+        let others: Vec<(usize, usize)> = cells.iter().copied().filter(|&cell| cell != start).collect();
+        let goal = pick_extreme(rng, &others, |cell| usize::MAX - key(cell));
+        (start, goal)
+    }
+}
+
+/// All floor cells in row-major order
+fn floor_cells(maze: &Maze) -> Vec<(usize, usize)> {
+    let mut floors = Vec::with_capacity(maze.rows * maze.cols / 2);
+    for y in 0..maze.rows {
+        for x in 0..maze.cols {
+            if maze.get_cell(x, y) {
+                floors.push((x, y));
+            }
+        }
+    }
+    floors
+}
+
+/// Two distinct cells, drawn as every generator did before placement was shared
+fn distinct_pair(rng: &mut Xoshiro256PlusPlus, cells: &[(usize, usize)]) -> Endpoints {
+    let start_idx = rng.gen_range(0..cells.len());
+    let mut goal_idx = rng.gen_range(0..cells.len());
+    while goal_idx == start_idx {
+        goal_idx = rng.gen_range(0..cells.len());
+    }
+    (cells[start_idx], cells[goal_idx])
+}
+
+fn floor_neighbors(maze: &Maze, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    DIRECTIONS.iter().filter_map(move |&(dx, dy)| {
+        let nx = x as i32 + dx;
+        let ny = y as i32 + dy;
+        if nx >= 0 && nx < maze.cols as i32 && ny >= 0 && ny < maze.rows as i32
+            && maze.get_cell(nx as usize, ny as usize)
+        {
+            Some((nx as usize, ny as usize))
+        } else {
+            None
+        }
+    })
+}
+
+/// BFS distance (in moves) from `from` to every cell; usize::MAX if unreachable
+fn distances(maze: &Maze, from: (usize, usize)) -> Vec<usize> {
+    let mut dist = vec![usize::MAX; maze.rows * maze.cols];
+    let mut queue = VecDeque::new();
+    dist[from.1 * maze.cols + from.0] = 0;
+    queue.push_back(from);
+    while let Some((x, y)) = queue.pop_front() {
+        let d = dist[y * maze.cols + x];
+        for (nx, ny) in floor_neighbors(maze, x, y) {
+            let idx = ny * maze.cols + nx;
+            if dist[idx] == usize::MAX {
+                dist[idx] = d + 1;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    dist
+}
+
+/// Cells of the largest 4-connected floor region, in discovery order
+fn largest_component(maze: &Maze) -> Vec<(usize, usize)> {
+    let mut seen = vec![false; maze.rows * maze.cols];
+    let mut best: Vec<(usize, usize)> = Vec::new();
+    let mut region = Vec::new();
+    for cell in floor_cells(maze) {
+        if seen[cell.1 * maze.cols + cell.0] {
+            continue;
+        }
+        
+        // Flood fill, using the region itself as the queue
+        region.clear();
+        seen[cell.1 * maze.cols + cell.0] = true;
+        region.push(cell);
+        let mut next = 0;
+        while next < region.len() {
+            let (x, y) = region[next];
+            next += 1;
+            for (nx, ny) in floor_neighbors(maze, x, y) {
+                if !seen[ny * maze.cols + nx] {
+                    seen[ny * maze.cols + nx] = true;
+                    region.push((nx, ny));
+                }
+            }
+        }
+        if region.len() > best.len() {
+            std::mem::swap(&mut best, &mut region);
+        }
+    }
+    best
+}
+
+/// A random cell among the farthest (by BFS) from `from`
+fn farthest(rng: &mut Xoshiro256PlusPlus, maze: &Maze, cells: &[(usize, usize)], from: (usize, usize)) -> (usize, usize) {
+    let dist = distances(maze, from);
+    pick_extreme(rng, cells, |(x, y)| usize::MAX - dist[y * maze.cols + x])
+}
+
+/// A random cell among those with the smallest key
+fn pick_extreme(
+    rng: &mut Xoshiro256PlusPlus,
+    cells: &[(usize, usize)],
+    key: impl Fn((usize, usize)) -> usize,
+) -> (usize, usize) {
+    let best = cells.iter().map(|&cell| key(cell)).min().unwrap();
+    let ties: Vec<(usize, usize)> = cells.iter().copied().filter(|&cell| key(cell) == best).collect();
+    ties[rng.gen_range(0..ties.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{braid, dfs};
+    use crate::parameters::get_placement_params;
+    use rand::SeedableRng;
+    
+    fn placement(policy: PlacementType, pairs: &[(&str, &str)]) -> Placement {
+        let pairs = pairs.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect();
+        let params = GeneratorParams::parse(&get_placement_params(policy), pairs, "test").unwrap();
+        Placement::new(policy, &params).unwrap()
+    }
+    
+    /// Placed mazes for a range of seeds, half of them braided so they have loops
+    fn placed(placement: &Placement) -> Vec<Maze> {
+        (0..40)
+            .map(|seed| {
+                let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+                let mut maze = dfs::generate(&mut rng, 15, 15);
+                if seed % 2 == 1 {
+                    braid::braid(&mut rng, &mut maze, 0.5);
+                }
+                placement.place(&mut rng, &mut maze).unwrap();
+                maze
+            })
+            .collect()
+    }
+    
+    fn start_goal_distance(maze: &Maze) -> usize {
+        distances(maze, maze.start)[maze.goal.1 * maze.cols + maze.goal.0]
+    }
+    
+    #[test]
+    fn exact_distance_places_the_goal_at_the_distance() {
+        for maze in placed(&placement(PlacementType::ExactDistance, &[("distance", "12")])) {
+            assert_eq!(start_goal_distance(&maze), 12);
+        }
+    }
+    
+    #[test]
+    fn band_places_the_goal_inside_the_band() {
+        let band = placement(PlacementType::Band, &[("min_distance", "5"), ("max_distance", "9")]);
+        for maze in placed(&band) {
+            assert!((5..=9).contains(&start_goal_distance(&maze)), "{:?} {:?}", maze.start, maze.goal);
+        }
+    }
+    
+    #[test]
+    fn dead_ends_places_start_and_goal_at_dead_ends() {
+        for maze in placed(&placement(PlacementType::DeadEnds, &[])) {
+            assert_ne!(maze.start, maze.goal);
+            for (x, y) in [maze.start, maze.goal] {
+                assert_eq!(floor_neighbors(&maze, x, y).count(), 1, "{} {}", x, y);
+            }
+        }
+    }
+    
+    #[test]
+    fn uniform_keeps_the_original_draws() {
+        let uniform = placement(PlacementType::Uniform, &[]);
+        for seed in 0..40 {
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+            let mut maze = dfs::generate(&mut rng, 15, 15);
+            let mut original = rng.clone();
+            uniform.place(&mut rng, &mut maze).unwrap();
+            
+            // The draws every generator made before placement was shared
+            let floors = floor_cells(&maze);
+            let start_idx = original.gen_range(0..floors.len());
+            let mut goal_idx = original.gen_range(0..floors.len());
+            while goal_idx == start_idx {
+                goal_idx = original.gen_range(0..floors.len());
+            }
+            assert_eq!((maze.start, maze.goal), (floors[start_idx], floors[goal_idx]));
+            assert_eq!(rng.gen::<u64>(), original.gen::<u64>(), "rng must be left where it was");
+        }
+    }
+}
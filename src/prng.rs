@@ -1,20 +1,85 @@
-use crate::{GeneratorType, SolverType};
+use crate::{GeneratorType, SeedScheme};
 use rand::SeedableRng;
 use rand_xoshiro::Xoshiro256PlusPlus;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
-/// Create deterministic PRNG for a specific instance
+/// Stream tag for donor-trace pairing ("SWAP")
+const SWAP_STREAM: u64 = 0x5357_4150;
+/// Stream tag for trace corruption ("XFRM")
+const TRANSFORM_STREAM: u64 = 0x5846_524D;
+/// Stream tag for ordering stratified selections ("STRA")
+const STRATIFY_STREAM: u64 = 0x5354_5241;
+
+/// Stable generator ids for seed derivation.
+/// These are part of the dataset format: never renumber, only append.
+fn generator_id(generator: GeneratorType) -> u64 {
+    match generator {
+        GeneratorType::Dfs => 1,
+        GeneratorType::Kruskal => 2,
+        GeneratorType::Wilson => 3,
+        GeneratorType::Searchformer => 4,
+        GeneratorType::DrunkardsWalk => 5,
+        GeneratorType::Prim => 6,
+        GeneratorType::GrowingTree => 7,
+        GeneratorType::RecursiveDivision => 8,
+        GeneratorType::Eller => 9,
+        GeneratorType::AldousBroder => 10,
+        GeneratorType::BinaryTree => 11,
+        GeneratorType::Sidewinder => 12,
+        GeneratorType::HuntAndKill => 13,
+        GeneratorType::CellularAutomata => 14,
+        GeneratorType::BspDungeon => 15,
+    }
+}
+
+/// Create deterministic PRNG for a specific instance.
+/// The solver is not part of the seed, so every solver sees the same mazes.
 pub fn create_instance_prng(
+    scheme: SeedScheme,
+    master_seed: u64,
+    generator: GeneratorType,
+    instance_id: u64,
+) -> Xoshiro256PlusPlus {
+    match scheme {
+        SeedScheme::V1 => seed_from_words(&[master_seed, generator_id(generator), instance_id]),
+        SeedScheme::Legacy => create_legacy_instance_prng(master_seed, generator, instance_id),
+    }
+}
+
+/// Discriminants the original enums' derived `Hash` wrote for each generator.
+/// Frozen by hand so adding or reordering variants cannot change legacy seeds.
+/// Generators added since have no legacy seeds.
+fn legacy_generator_discriminant(generator: GeneratorType) -> Option<isize> {
+    match generator {
+        GeneratorType::Dfs => Some(0),
+        GeneratorType::Kruskal => Some(1),
+        GeneratorType::Wilson => Some(2),
+        GeneratorType::Searchformer => Some(3),
+        GeneratorType::DrunkardsWalk => Some(4),
+        _ => None,
+    }
+}
+
+/// Whether the legacy scheme can seed a generator (only the original five)
+pub fn has_legacy_seeds(generator: GeneratorType) -> bool {
+    legacy_generator_discriminant(generator).is_some()
+}
+
+/// Original derivation through std's DefaultHasher, with the byte stream the
+/// original enums' derived `Hash` produced: the generator's discriminant as an
+/// isize and nothing for the solver (A* was the only variant, and a
+/// single-variant enum hashes to nothing).
+/// Its output may change with the Rust toolchain; kept to reproduce existing datasets.
+fn create_legacy_instance_prng(
     master_seed: u64,
     generator: GeneratorType,
-    solver: SolverType,
     instance_id: u64,
 ) -> Xoshiro256PlusPlus {
     let mut hasher = DefaultHasher::new();
     master_seed.hash(&mut hasher);
-    generator.hash(&mut hasher);
-    solver.hash(&mut hasher);
+    let discriminant = legacy_generator_discriminant(generator).expect("legacy seeds only exist for the original generators");
+    hasher.write_isize(discriminant);
     instance_id.hash(&mut hasher);
     
     let hash1 = hasher.finish();
@@ -33,4 +98,89 @@ pub fn create_instance_prng(
     bytes[16..24].copy_from_slice(&hash3.to_le_bytes());
     bytes[24..32].copy_from_slice(&hash4.to_le_bytes());
     Xoshiro256PlusPlus::from_seed(bytes)
-}
\ No newline at end of file
+}
+
+/// Create deterministic PRNG for pairing the instances of one swap block
+pub fn create_swap_prng(master_seed: u64, block: u64) -> Xoshiro256PlusPlus {
+    seed_from_words(&[master_seed, SWAP_STREAM, block])
+}
+
+/// Create deterministic PRNG for corrupting a specific instance's trace
+pub fn create_transform_prng(master_seed: u64, instance_id: u64) -> Xoshiro256PlusPlus {
+    seed_from_words(&[master_seed, TRANSFORM_STREAM, instance_id])
+}
+
+/// Create deterministic PRNG for ordering the selected candidates of one stratified block
+pub fn create_stratify_prng(master_seed: u64, block: u64) -> Xoshiro256PlusPlus {
+    seed_from_words(&[master_seed, STRATIFY_STREAM, block])
+}
+
+/// SplitMix64 step (Steele, Lea & Flood 2014)
+#[inline(always)]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Absorb a sequence of words with SplitMix64 and expand to a 256-bit seed.
+/// Each word is XORed into the state, which is then advanced by one
+/// SplitMix64 step; four further steps produce the Xoshiro seed (little endian).
+fn seed_from_words(words: &[u64]) -> Xoshiro256PlusPlus {
+    Xoshiro256PlusPlus::from_seed(seed_bytes(words))
+}
+
+fn seed_bytes(words: &[u64]) -> [u8; 32] {
+    let mut state = 0u64;
+    for &word in words {
+        state ^= word;
+        state = splitmix64(&mut state);
+    }
+    
+    let mut bytes = [0u8; 32];
+    for chunk in bytes.chunks_exact_mut(8) {
+        chunk.copy_from_slice(&splitmix64(&mut state).to_le_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngCore;
+    
+    /// First outputs of legacy instance PRNGs, recorded from the pre-v1 build
+    #[test]
+    fn legacy_seeds_match_original_build() {
+        let golden = [
+            (GeneratorType::Dfs, 0, 0x3b59_4a2a_82d9_1929),
+            (GeneratorType::Wilson, 7, 0x8c6a_f7d5_45f2_1d9f),
+            (GeneratorType::DrunkardsWalk, 123_456_789, 0x93b1_75bf_efcf_9478),
+        ];
+        for (generator, instance_id, expected) in golden {
+            let mut rng = create_instance_prng(SeedScheme::Legacy, 12345, generator, instance_id);
+            assert_eq!(rng.next_u64(), expected, "{:?} instance {}", generator, instance_id);
+        }
+    }
+    
+    /// First seed word and first output of v1 instance PRNGs. These are part
+    /// of the dataset format: if this fails, v1 datasets no longer reproduce.
+    #[test]
+    fn v1_seeds_are_frozen() {
+        let golden = [
+            (0, GeneratorType::Dfs, 0, 0xd1c0_2706_8798_4b37, 0x74a9_3a92_9f98_f7a6),
+            (12345, GeneratorType::Wilson, 7, 0x08f1_134a_0faa_a7f1, 0x4acf_3ac1_e605_8154),
+            (42, GeneratorType::BspDungeon, 123_456_789, 0x1dee_11e6_c151_54e7, 0x402e_3f5e_b9a6_14e4),
+            (u64::MAX, GeneratorType::Searchformer, u64::MAX, 0xe064_d226_7b3c_2c8a, 0x31aa_4fa6_cab5_0726),
+        ];
+        for (master_seed, generator, instance_id, seed_word, first) in golden {
+            let seed = seed_bytes(&[master_seed, generator_id(generator), instance_id]);
+            let mut rng = create_instance_prng(SeedScheme::V1, master_seed, generator, instance_id);
+            let context = format!("seed {} {:?} instance {}", master_seed, generator, instance_id);
+            assert_eq!(u64::from_le_bytes(seed[..8].try_into().unwrap()), seed_word, "{}", context);
+            assert_eq!(rng.next_u64(), first, "{}", context);
+        }
+    }
+}
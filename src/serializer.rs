@@ -1,5 +1,5 @@
 use crate::types::{MazeResult, ReasoningEvent};
-use crate::{GeneratorType, SolverType};
+use crate::{GeneratorType, PlacementType, SeedScheme, SolverType, TransformType};
 use std::io::Write;
 use std::fmt::Write as FmtWrite;
 
@@ -56,6 +56,18 @@ pub fn write_maze_json<W: Write>(
                 write!(buffer, "{} {} c{} c{}", x, y, g, h).unwrap();
                 writer.write_all(buffer.as_bytes())?;
             }
+            ReasoningEvent::Dequeue { x, y, depth } => {
+                writer.write_all(b" dequeue ")?;
+                buffer.clear();
+                write!(buffer, "{} {} c{}", x, y, depth).unwrap();
+                writer.write_all(buffer.as_bytes())?;
+            }
+            ReasoningEvent::Enqueue { x, y, depth } => {
+                writer.write_all(b" enqueue ")?;
+                buffer.clear();
+                write!(buffer, "{} {} c{}", x, y, depth).unwrap();
+                writer.write_all(buffer.as_bytes())?;
+            }
         }
     }
     
@@ -78,11 +90,24 @@ pub fn write_maze_json<W: Write>(
         GeneratorType::Wilson => writer.write_all(b"wilson")?,
         GeneratorType::Searchformer => writer.write_all(b"searchformer")?,
         GeneratorType::DrunkardsWalk => writer.write_all(b"drunkardswalk")?,
+        GeneratorType::Prim => writer.write_all(b"prim")?,
+        GeneratorType::GrowingTree => writer.write_all(b"growingtree")?,
+        GeneratorType::RecursiveDivision => writer.write_all(b"recursivedivision")?,
+        GeneratorType::Eller => writer.write_all(b"eller")?,
+        GeneratorType::AldousBroder => writer.write_all(b"aldousbroder")?,
+        GeneratorType::BinaryTree => writer.write_all(b"binarytree")?,
+        GeneratorType::Sidewinder => writer.write_all(b"sidewinder")?,
+        GeneratorType::HuntAndKill => writer.write_all(b"huntandkill")?,
+        GeneratorType::CellularAutomata => writer.write_all(b"cellularautomata")?,
+        GeneratorType::BspDungeon => writer.write_all(b"bspdungeon")?,
     }
     
     writer.write_all(b"\",\"solver\":\"")?;
     match result.solver {
         SolverType::AStar => writer.write_all(b"astar")?,
+        SolverType::Bfs => writer.write_all(b"bfs")?,
+        SolverType::Dijkstra => writer.write_all(b"dijkstra")?,
+        SolverType::Greedy => writer.write_all(b"greedy")?,
     }
     
     writer.write_all(b"\",\"seed\":")?;
@@ -90,6 +115,13 @@ pub fn write_maze_json<W: Write>(
     write!(buffer, "{}", result.seed).unwrap();
     writer.write_all(buffer.as_bytes())?;
     
+    writer.write_all(b",\"seed_scheme\":\"")?;
+    match result.seed_scheme {
+        SeedScheme::V1 => writer.write_all(b"v1")?,
+        SeedScheme::Legacy => writer.write_all(b"legacy")?,
+    }
+    writer.write_all(b"\"")?;
+    
     writer.write_all(b",\"rows\":")?;
     buffer.clear();
     write!(buffer, "{}", result.maze.rows).unwrap();
@@ -100,6 +132,61 @@ pub fn write_maze_json<W: Write>(
     write!(buffer, "{}", result.maze.cols).unwrap();
     writer.write_all(buffer.as_bytes())?;
     
+    if result.placement != PlacementType::Uniform {
+        writer.write_all(b",\"placement\":\"")?;
+        match result.placement {
+            PlacementType::Uniform => writer.write_all(b"uniform")?,
+            PlacementType::Diameter => writer.write_all(b"diameter")?,
+            PlacementType::Band => writer.write_all(b"band")?,
+            PlacementType::ExactDistance => writer.write_all(b"exactdistance")?,
+            PlacementType::DeadEnds => writer.write_all(b"deadends")?,
+            PlacementType::Opposite => writer.write_all(b"opposite")?,
+        }
+        writer.write_all(b"\"")?;
+    }
+    
+    if let Some(candidate) = result.candidate {
+        writer.write_all(b",\"candidate\":")?;
+        buffer.clear();
+        write!(buffer, "{}", candidate).unwrap();
+        writer.write_all(buffer.as_bytes())?;
+    }
+    
+    if let Some(optimal) = result.plan_optimal {
+        writer.write_all(b",\"plan_optimal\":")?;
+        writer.write_all(if optimal { b"true" } else { b"false" })?;
+    }
+    
+    if let Some(donor) = result.trace_donor {
+        writer.write_all(b",\"trace_donor\":")?;
+        buffer.clear();
+        write!(buffer, "{}", donor).unwrap();
+        writer.write_all(buffer.as_bytes())?;
+    }
+    
+    if let Some(transform) = &result.trace_transform {
+        writer.write_all(b",\"trace_transform\":{\"kind\":\"")?;
+        match transform.transform {
+            TransformType::None => writer.write_all(b"none")?,
+            TransformType::Shuffle => writer.write_all(b"shuffle")?,
+            TransformType::Drop => writer.write_all(b"drop")?,
+            TransformType::Truncate => writer.write_all(b"truncate")?,
+            TransformType::Perturb => writer.write_all(b"perturb")?,
+            TransformType::Inject => writer.write_all(b"inject")?,
+        }
+        writer.write_all(b"\",\"rate\":")?;
+        buffer.clear();
+        write!(buffer, "{}", transform.rate).unwrap();
+        writer.write_all(buffer.as_bytes())?;
+        if transform.transform == TransformType::Perturb {
+            writer.write_all(b",\"magnitude\":")?;
+            buffer.clear();
+            write!(buffer, "{}", transform.magnitude).unwrap();
+            writer.write_all(buffer.as_bytes())?;
+        }
+        writer.write_all(b"}")?;
+    }
+    
     writer.write_all(b"}\n")?;
     
     Ok(())
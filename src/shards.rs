@@ -0,0 +1,157 @@
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+/// Contiguous range of instance ids generated by one shard.
+/// Shard sizes differ by at most one instance.
+pub fn shard_range(count: u64, num_shards: u64, shard_index: u64) -> (u64, u64) {
+    let bound = |i: u64| (count as u128 * i as u128 / num_shards as u128) as u64;
+    (bound(shard_index), bound(shard_index + 1))
+}
+
+/// Read the instance id from a line written by `serializer::write_maze_json`
+pub fn line_idx(line: &str) -> Option<u64> {
+    let rest = line.strip_prefix("{\"idx\":")?;
+    let end = rest.find(',')?;
+    rest[..end].parse().ok()
+}
+
+/// Open a shard file, naming it in the error
+fn open(path: &str) -> Result<File, String> {
+    File::open(path).map_err(|e| format!("{}: {}", path, e))
+}
+
+/// First instance id of a shard file, or None if it is empty
+fn first_idx(path: &str) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+    let mut line = String::new();
+    BufReader::new(open(path)?).read_line(&mut line)?;
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    line_idx(&line)
+        .map(Some)
+        .ok_or_else(|| format!("{}:1: line does not start with an idx field", path).into())
+}
+
+/// Refuse an output path that names one of the inputs, which creating the
+/// output would truncate before it is read
+fn check_output_not_input(output_path: &str, inputs: &[String]) -> Result<(), String> {
+    // An output that does not exist yet cannot be an input
+    let Ok(output) = fs::canonicalize(output_path) else {
+        return Ok(());
+    };
+    for path in inputs {
+        if fs::canonicalize(path).map_err(|e| format!("{}: {}", path, e))? == output {
+            return Err(format!("output {} is also an input shard ({})", output_path, path));
+        }
+    }
+    Ok(())
+}
+
+/// Concatenate shard files into one dataset, ordered by their first instance id.
+/// Verifies that the ids run 0, 1, 2, ... without gaps or duplicates, and
+/// optionally that the dataset holds exactly `expected_count` instances.
+/// Without an output path the shards are only verified. If verification
+/// fails, the partially written output is removed.
+pub fn merge(
+    inputs: &[String],
+    output_path: Option<&str>,
+    expected_count: Option<u64>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(path) = output_path {
+        check_output_not_input(path, inputs)?;
+    }
+    
+    let mut shards = Vec::with_capacity(inputs.len());
+    for path in inputs {
+        if let Some(first) = first_idx(path)? {
+            shards.push((first, path.as_str()));
+        }
+    }
+    shards.sort();
+    
+    let mut writer = match output_path {
+        Some(path) => Some(BufWriter::with_capacity(
+            8 * 1024 * 1024,
+            File::create(path).map_err(|e| format!("{}: {}", path, e))?,
+        )),
+        None => None,
+    };
+    
+    let result = concat(&shards, writer.as_mut()).and_then(|total| match expected_count {
+        Some(expected) if total != expected => {
+            Err(format!("shards hold {} instances, expected {}", total, expected).into())
+        }
+        _ => Ok(total),
+    });
+    let total = match (result, output_path) {
+        (Ok(total), _) => total,
+        (Err(e), Some(path)) => {
+            drop(writer);
+            fs::remove_file(path)?;
+            return Err(e);
+        }
+        (Err(e), None) => return Err(e),
+    };
+    println!("Verified {} instances (idx 0..{})", total, total);
+    Ok(())
+}
+
+/// Copy the lines of sorted shards to `writer` (if any), checking that the
+/// ids run 0, 1, 2, ...; returns the number of instances
+fn concat(
+    shards: &[(u64, &str)],
+    mut writer: Option<&mut BufWriter<File>>,
+) -> Result<u64, Box<dyn std::error::Error>> {
+    let mut next_idx = 0u64;
+    for &(_, path) in shards {
+        let mut reader = BufReader::new(open(path)?);
+        let mut line = String::new();
+        let mut line_no = 0;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            line_no += 1;
+            if !line.ends_with('\n') {
+                return Err(format!("{}:{}: truncated last line", path, line_no).into());
+            }
+            let idx = line_idx(&line)
+                .ok_or_else(|| format!("{}:{}: line does not start with an idx field", path, line_no))?;
+            if idx != next_idx {
+                return Err(format!("{}:{}: expected idx {}, found {}", path, line_no, next_idx, idx).into());
+            }
+            next_idx += 1;
+            
+            if let Some(writer) = writer.as_mut() {
+                writer.write_all(line.as_bytes())?;
+            }
+        }
+    }
+    
+    if let Some(writer) = writer {
+        writer.flush()?;
+    }
+    Ok(next_idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn shard_ranges_partition_the_instances() {
+        for count in [0, 1, 7, 1000, 1001, 123_457] {
+            for num_shards in [1, 2, 3, 7, 16, 2000] {
+                let mut next = 0;
+                for shard_index in 0..num_shards {
+                    let (start, end) = shard_range(count, num_shards, shard_index);
+                    assert_eq!(start, next, "count {} shard {}/{}", count, shard_index, num_shards);
+                    assert!(end >= start && end - start <= count / num_shards + 1);
+                    next = end;
+                }
+                assert_eq!(next, count, "count {} over {} shards", count, num_shards);
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::reconstruct_path;
 use crate::types::{Maze, Solution, ReasoningEvent};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -5,10 +6,10 @@ use std::collections::BinaryHeap;
 /// A* node for priority queue
 #[derive(Copy, Clone, Eq, PartialEq)]
 struct AStarNode {
-    x: u16,
-    y: u16,
-    g_score: u16,
-    f_score: u16,
+    x: u32,
+    y: u32,
+    g_score: u32,
+    f_score: u32,
 }
 
 impl Ord for AStarNode {
@@ -27,40 +28,54 @@ impl PartialOrd for AStarNode {
 
 /// Manhattan distance heuristic
 #[inline(always)]
-fn manhattan_distance(x1: u16, y1: u16, x2: u16, y2: u16) -> u16 {
-    ((x1 as i32 - x2 as i32).abs() + (y1 as i32 - y2 as i32).abs()) as u16
+pub fn manhattan_distance(x1: u32, y1: u32, x2: u32, y2: u32) -> u32 {
+    x1.abs_diff(x2) + y1.abs_diff(y2)
 }
 
 /// A* pathfinding with reasoning trace
 pub fn solve(maze: &Maze) -> Solution {
+    let (goal_x, goal_y) = (maze.goal.0 as u32, maze.goal.1 as u32);
+    search(maze, |x, y| manhattan_distance(x, y, goal_x, goal_y), |_, _| 1, |g, h| g + h)
+}
+
+/// Best-first search recording close/create events.
+///
+/// `heuristic` gives h for a cell, `cost` the cost of stepping onto a cell
+/// and `priority` the f that orders the open set, from g and h (ties go to
+/// the larger g). `solve` uses Manhattan distance, unit costs and g + h.
+pub fn search(
+    maze: &Maze,
+    heuristic: impl Fn(u32, u32) -> u32,
+    cost: impl Fn(usize, usize) -> u32,
+    priority: impl Fn(u32, u32) -> u32,
+) -> Solution {
     let mut reasoning: Vec<ReasoningEvent> = Vec::with_capacity(1000);
     let mut open_set = BinaryHeap::with_capacity(256);
     
     // Use flat arrays for better cache locality
     let total_cells = maze.rows * maze.cols;
-    let mut g_scores = vec![u16::MAX; total_cells];
+    let mut g_scores = vec![u32::MAX; total_cells];
     let mut came_from = vec![u32::MAX; total_cells];
     let mut closed_set = vec![false; total_cells];
     
-    // Convert coordinates to u16 for efficiency
-    let start_x = maze.start.0 as u16;
-    let start_y = maze.start.1 as u16;
-    let goal_x = maze.goal.0 as u16;
-    let goal_y = maze.goal.1 as u16;
-    let _cols = maze.cols as u16;
+    // Coordinates and costs are u32 (trace events use the same width)
+    let start_x = maze.start.0 as u32;
+    let start_y = maze.start.1 as u32;
+    let goal_x = maze.goal.0 as u32;
+    let goal_y = maze.goal.1 as u32;
     
     // Initialize start node
     let start_idx = (start_y as usize) * maze.cols + (start_x as usize);
-    let start_h = manhattan_distance(start_x, start_y, goal_x, goal_y);
+    let start_h = heuristic(start_x, start_y);
     g_scores[start_idx] = 0;
     open_set.push(AStarNode {
         x: start_x,
         y: start_y,
         g_score: 0,
-        f_score: start_h,
+        f_score: priority(0, start_h),
     });
     
-    const DIRECTIONS: [(i16, i16); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    const DIRECTIONS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
     
     while let Some(current_node) = open_set.pop() {
         let x = current_node.x;
@@ -73,12 +88,12 @@ pub fn solve(maze: &Maze) -> Solution {
         }
         
         let g_score = current_node.g_score;
-        let h_score = manhattan_distance(x, y, goal_x, goal_y);
+        let h_score = heuristic(x, y);
         
         // Record close event
         reasoning.push(ReasoningEvent::Close { 
-            x: x, 
-            y: y, 
+            x, 
+            y, 
             g: g_score, 
             h: h_score 
         });
@@ -92,16 +107,16 @@ pub fn solve(maze: &Maze) -> Solution {
         
         // Explore neighbors
         for &(dx, dy) in &DIRECTIONS {
-            let nx = x as i16 + dx;
-            let ny = y as i16 + dy;
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
             
             // Check bounds
-            if nx < 0 || nx >= maze.cols as i16 || ny < 0 || ny >= maze.rows as i16 {
+            if nx < 0 || nx >= maze.cols as i32 || ny < 0 || ny >= maze.rows as i32 {
                 continue;
             }
             
-            let nx = nx as u16;
-            let ny = ny as u16;
+            let nx = nx as u32;
+            let ny = ny as u32;
             let neighbor_idx = (ny as usize) * maze.cols + (nx as usize);
             
             // Skip walls and closed nodes
@@ -109,15 +124,17 @@ pub fn solve(maze: &Maze) -> Solution {
                 continue;
             }
             
-            let tentative_g = g_score + 1;
+            let tentative_g = g_score
+                .checked_add(cost(nx as usize, ny as usize))
+                .expect("path cost overflows u32");
             
             // Update if this is a better path
             if tentative_g < g_scores[neighbor_idx] {
                 came_from[neighbor_idx] = current_idx as u32;
                 g_scores[neighbor_idx] = tentative_g;
                 
-                let h = manhattan_distance(nx, ny, goal_x, goal_y);
-                let f = tentative_g + h;
+                let h = heuristic(nx, ny);
+                let f = priority(tentative_g, h);
                 
                 // Record create event
                 reasoning.push(ReasoningEvent::Create { 
@@ -137,32 +154,7 @@ pub fn solve(maze: &Maze) -> Solution {
         }
     }
     
-    // Reconstruct path
-    let mut path = Vec::with_capacity(100);
-    let goal_idx = (goal_y as usize) * maze.cols + (goal_x as usize);
-    let mut current_idx = goal_idx;
-    
-    // Check if goal was reached
-    if came_from[goal_idx] != u32::MAX || current_idx == start_idx {
-        while current_idx != start_idx {
-            let x = (current_idx % maze.cols) as usize;
-            let y = (current_idx / maze.cols) as usize;
-            path.push((x, y));
-            
-            let prev_idx = came_from[current_idx];
-            if prev_idx == u32::MAX {
-                // No path found
-                path.clear();
-                break;
-            }
-            current_idx = prev_idx as usize;
-        }
-        
-        if !path.is_empty() {
-            path.push(maze.start);
-            path.reverse();
-        }
-    }
+    let path = reconstruct_path(maze, &came_from);
     
     Solution { path, reasoning }
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use super::reconstruct_path;
+use crate::types::{Maze, Solution, ReasoningEvent};
+use std::collections::VecDeque;
+
+/// Breadth-first search with reasoning trace.
+///
+/// Cells are marked when enqueued, so each floor cell enters the queue at
+/// most once, at its BFS depth. Like A*, the search stops when the goal is
+/// dequeued, which makes the trace comparable event for event.
+pub fn solve(maze: &Maze) -> Solution {
+    let mut reasoning: Vec<ReasoningEvent> = Vec::with_capacity(1000);
+    let mut queue = VecDeque::with_capacity(256);
+    
+    let total_cells = maze.rows * maze.cols;
+    let mut depths = vec![u32::MAX; total_cells];
+    let mut came_from = vec![u32::MAX; total_cells];
+    
+    let start_x = maze.start.0 as u32;
+    let start_y = maze.start.1 as u32;
+    let goal_x = maze.goal.0 as u32;
+    let goal_y = maze.goal.1 as u32;
+    
+    let start_idx = (start_y as usize) * maze.cols + (start_x as usize);
+    depths[start_idx] = 0;
+    queue.push_back((start_x, start_y));
+    
+    const DIRECTIONS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    
+    while let Some((x, y)) = queue.pop_front() {
+        let current_idx = (y as usize) * maze.cols + (x as usize);
+        let depth = depths[current_idx];
+        
+        reasoning.push(ReasoningEvent::Dequeue { x, y, depth });
+        
+        if x == goal_x && y == goal_y {
+            break;
+        }
+        
+        for &(dx, dy) in &DIRECTIONS {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+            
+            if nx < 0 || nx >= maze.cols as i32 || ny < 0 || ny >= maze.rows as i32 {
+                continue;
+            }
+            
+            let nx = nx as u32;
+            let ny = ny as u32;
+            let neighbor_idx = (ny as usize) * maze.cols + (nx as usize);
+            
+            // Skip walls and cells already enqueued
+            if !maze.get_cell(nx as usize, ny as usize) || depths[neighbor_idx] != u32::MAX {
+                continue;
+            }
+            
+            came_from[neighbor_idx] = current_idx as u32;
+            depths[neighbor_idx] = depth + 1;
+            reasoning.push(ReasoningEvent::Enqueue { x: nx, y: ny, depth: depth + 1 });
+            queue.push_back((nx, ny));
+        }
+    }
+    
+    let path = reconstruct_path(maze, &came_from);
+    
+    Solution { path, reasoning }
+}
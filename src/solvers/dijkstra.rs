@@ -0,0 +1,16 @@
+use super::astar;
+use crate::types::{Maze, Solution};
+
+/// Dijkstra's algorithm with reasoning trace.
+///
+/// This is A* with h = 0: the trace has the same close/create events as
+/// `astar::solve`, with h always 0, so it shows an uninformed search.
+pub fn solve(maze: &Maze) -> Solution {
+    solve_weighted(maze, |_, _| 1)
+}
+
+/// Dijkstra's algorithm where stepping onto cell (x, y) costs `cost(x, y)`.
+/// Costs should be at least 1; g values in the trace are the summed costs.
+pub fn solve_weighted(maze: &Maze, cost: impl Fn(usize, usize) -> u32) -> Solution {
+    astar::search(maze, |_, _| 0, cost, |g, h| g + h)
+}
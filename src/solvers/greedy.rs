@@ -0,0 +1,12 @@
+use super::astar::{self, manhattan_distance};
+use crate::types::{Maze, Solution};
+
+/// Greedy best-first search with reasoning trace.
+///
+/// A*'s search with the open set ordered by h alone: it heads straight for
+/// the goal and often returns a plan longer than the shortest path. The
+/// trace has the A* close/create format, with g the cost along the plan found.
+pub fn solve(maze: &Maze) -> Solution {
+    let (goal_x, goal_y) = (maze.goal.0 as u32, maze.goal.1 as u32);
+    astar::search(maze, |x, y| manhattan_distance(x, y, goal_x, goal_y), |_, _| 1, |_, h| h)
+}
@@ -1,4 +1,7 @@
 pub mod astar;
+pub mod bfs;
+pub mod dijkstra;
+pub mod greedy;
 
 use crate::types::{Maze, Solution};
 use crate::SolverType;
@@ -6,5 +9,49 @@ use crate::SolverType;
 pub fn solve_maze(solver: SolverType, maze: &Maze) -> Solution {
     match solver {
         SolverType::AStar => astar::solve(maze),
+        SolverType::Bfs => bfs::solve(maze),
+        SolverType::Dijkstra => dijkstra::solve(maze),
+        SolverType::Greedy => greedy::solve(maze),
     }
-}
\ No newline at end of file
+}
+
+/// Whether a solver's plan is as short as the optimal one, for solvers that
+/// do not guarantee it (None for the optimal solvers)
+pub fn plan_optimal(solver: SolverType, maze: &Maze, solution: &Solution) -> Option<bool> {
+    match solver {
+        SolverType::AStar | SolverType::Bfs | SolverType::Dijkstra => None,
+        SolverType::Greedy => Some(solution.path.len() == astar::solve(maze).path.len()),
+    }
+}
+
+/// Walk `came_from` back from the goal; empty if the goal was not reached
+pub fn reconstruct_path(maze: &Maze, came_from: &[u32]) -> Vec<(usize, usize)> {
+    let mut path = Vec::with_capacity(100);
+    let start_idx = maze.start.1 * maze.cols + maze.start.0;
+    let goal_idx = maze.goal.1 * maze.cols + maze.goal.0;
+    let mut current_idx = goal_idx;
+    
+    // Check if goal was reached
+    if came_from[goal_idx] != u32::MAX || current_idx == start_idx {
+        while current_idx != start_idx {
+            let x = current_idx % maze.cols;
+            let y = current_idx / maze.cols;
+            path.push((x, y));
+            
+            let prev_idx = came_from[current_idx];
+            if prev_idx == u32::MAX {
+                // No path found
+                path.clear();
+                break;
+            }
+            current_idx = prev_idx as usize;
+        }
+        
+        if !path.is_empty() {
+            path.push(maze.start);
+            path.reverse();
+        }
+    }
+    
+    path
+}
@@ -0,0 +1,286 @@
+use crate::prng::create_stratify_prng;
+use crate::blocks::{block_of, block_range};
+use crate::types::Solution;
+use crate::StratifyBy;
+use rand::seq::SliceRandom;
+use rayon::prelude::*;
+
+/// Candidates scored in parallel between sequential acceptance passes
+const CHUNK_SIZE: u64 = 1000;
+
+/// Target histogram of path or trace lengths, as per-block quotas.
+///
+/// Instances are split into the same blocks as swapped traces; each block
+/// has its own quotas and its own range of candidate ids, so a block can be
+/// selected without looking at any other.
+pub struct Strata {
+    by: StratifyBy,
+    /// Bucket i holds lengths in [edges[i], edges[i + 1])
+    edges: Vec<u64>,
+    count: u64,
+    /// Per-bucket quotas of each block
+    block_quotas: Vec<Vec<u64>>,
+    /// Candidate ids reserved for each block
+    block_candidates: u64,
+}
+
+/// Candidates chosen for a run of whole blocks
+pub struct Selection {
+    start: u64,
+    candidates: Vec<u64>,
+    /// Candidates accepted and tried by the blocks that start inside the
+    /// selected range, so adjacent ranges count each block once
+    pub accepted: u64,
+    pub tried: u64,
+}
+
+impl Selection {
+    /// Candidate that seeds an instance
+    #[inline(always)]
+    pub fn candidate(&self, instance_id: u64) -> u64 {
+        self.candidates[(instance_id - self.start) as usize]
+    }
+}
+
+impl Strata {
+    /// Split `count` instances over the buckets in proportion to `weights`
+    /// (equal if empty). Each block gets the quotas that keep all blocks up
+    /// to it closest to the target shares, and `max_candidates` is divided
+    /// evenly between the blocks.
+    pub fn new(
+        by: StratifyBy,
+        edges: Vec<u64>,
+        weights: Vec<f64>,
+        count: u64,
+        max_candidates: u64,
+    ) -> Result<Self, String> {
+        if edges.len() < 2 || edges.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err("--bucket-edges needs at least two strictly increasing values".into());
+        }
+        let num_buckets = edges.len() - 1;
+        let weights = if weights.is_empty() { vec![1.0; num_buckets] } else { weights };
+        if weights.len() != num_buckets {
+            return Err(format!(
+                "--bucket-weights has {} values but --bucket-edges defines {} buckets",
+                weights.len(), num_buckets
+            ));
+        }
+        if weights.iter().any(|&w| !w.is_finite() || w < 0.0) || weights.iter().sum::<f64>() <= 0.0 {
+            return Err("--bucket-weights must be non-negative with a positive sum".into());
+        }
+        
+        let total: f64 = weights.iter().sum();
+        let num_blocks = if count == 0 { 0 } else { block_of(count, count - 1) + 1 };
+        let mut block_quotas = Vec::with_capacity(num_blocks as usize);
+        let mut assigned = vec![0u64; num_buckets];
+        for block in 0..num_blocks {
+            let (start, end) = block_range(count, block);
+            // Shortfall of each bucket against its share of instances 0..end
+            let targets: Vec<f64> = (0..num_buckets)
+                .map(|i| end as f64 * weights[i] / total - assigned[i] as f64)
+                .collect();
+            let quotas = apportion(end - start, &targets);
+            for (sum, quota) in assigned.iter_mut().zip(&quotas) {
+                *sum += quota;
+            }
+            block_quotas.push(quotas);
+        }
+        
+        Ok(Strata {
+            by,
+            edges,
+            count,
+            block_quotas,
+            block_candidates: max_candidates / num_blocks.max(1),
+        })
+    }
+    
+    /// Bucket of a solved instance, or None if its length is outside every bucket
+    pub fn bucket(&self, solution: &Solution) -> Option<usize> {
+        let length = match self.by {
+            StratifyBy::PathLength => solution.path.len(),
+            StratifyBy::TraceLength => solution.reasoning.len(),
+        } as u64;
+        match self.edges.partition_point(|&edge| edge <= length) {
+            0 => None,
+            i if i == self.edges.len() => None,
+            i => Some(i - 1),
+        }
+    }
+    
+    /// Choose the candidates of every block that overlaps instances `start..end`.
+    ///
+    /// Blocks are independent: each one depends only on the seed and
+    /// settings, so each output batch selects just the blocks it writes,
+    /// and the file is the same for any thread count.
+    pub fn select(
+        &self,
+        master_seed: u64,
+        start: u64,
+        end: u64,
+        score: impl Fn(u64) -> Result<Option<usize>, String> + Sync,
+    ) -> Result<Selection, String> {
+        let first_block = block_of(self.count, start);
+        let blocks: Vec<(u64, Vec<u64>, u64)> = (first_block..=block_of(self.count, end - 1))
+            .into_par_iter()
+            .map(|block| {
+                let (selected, tried) = self.select_block(master_seed, block, &score)?;
+                Ok((block, selected, tried))
+            })
+            .collect::<Result<_, String>>()?;
+        
+        let counted = blocks.iter().filter(|&&(block, _, _)| block_range(self.count, block).0 >= start);
+        let (accepted, tried) = counted.fold((0, 0), |(accepted, tried), (_, selected, block_tried)| {
+            (accepted + selected.len() as u64, tried + block_tried)
+        });
+        Ok(Selection {
+            start: block_range(self.count, first_block).0,
+            candidates: blocks.into_iter().flat_map(|(_, selected, _)| selected).collect(),
+            accepted,
+            tried,
+        })
+    }
+    
+    /// Fill one block's quotas from its candidate range, returning the chosen
+    /// candidates and how many were tried.
+    ///
+    /// Candidates are visited in id order and each is accepted if its bucket
+    /// still has quota left. Common buckets fill first, so the accepted
+    /// candidates are then put in a seed-derived random order.
+    fn select_block(
+        &self,
+        master_seed: u64,
+        block: u64,
+        score: &(impl Fn(u64) -> Result<Option<usize>, String> + Sync),
+    ) -> Result<(Vec<u64>, u64), String> {
+        let quotas = &self.block_quotas[block as usize];
+        let needed: u64 = quotas.iter().sum();
+        let first = block * self.block_candidates;
+        let last = first + self.block_candidates;
+        
+        let mut selected = Vec::with_capacity(needed as usize);
+        let mut filled = vec![0u64; quotas.len()];
+        let mut next = first;
+        
+        while (selected.len() as u64) < needed && next < last {
+            let chunk_end = (next + CHUNK_SIZE).min(last);
+            let buckets: Vec<Option<usize>> = (next..chunk_end)
+                .into_par_iter()
+                .map(score)
+                .collect::<Result<_, _>>()?;
+            
+            for (candidate, bucket) in (next..chunk_end).zip(buckets) {
+                if let Some(bucket) = bucket {
+                    if filled[bucket] < quotas[bucket] {
+                        filled[bucket] += 1;
+                        selected.push(candidate);
+                        if selected.len() as u64 == needed {
+                            break;
+                        }
+                    }
+                }
+            }
+            next = chunk_end;
+        }
+        
+        if (selected.len() as u64) < needed {
+            let unfilled: Vec<String> = (0..quotas.len())
+                .filter(|&i| filled[i] < quotas[i])
+                .map(|i| format!("[{}, {}): {}/{}", self.edges[i], self.edges[i + 1], filled[i], quotas[i]))
+                .collect();
+            return Err(format!(
+                "stratified sampling: quotas of block {} not filled after {} candidates ({}); \
+                 raise --max-candidates or adjust the buckets",
+                block,
+                self.block_candidates,
+                unfilled.join(", ")
+            ));
+        }
+        
+        let tried = selected.last().map_or(0, |&candidate| candidate + 1 - first);
+        selected.shuffle(&mut create_stratify_prng(master_seed, block));
+        Ok((selected, tried))
+    }
+}
+
+/// Split `seats` into non-negative quotas as close to `targets` as possible:
+/// round down, then settle the difference on the largest (or smallest) remainders.
+fn apportion(seats: u64, targets: &[f64]) -> Vec<u64> {
+    let mut quotas: Vec<u64> = targets.iter().map(|&target| target.max(0.0).floor() as u64).collect();
+    let remainder = |quotas: &[u64], i: usize| targets[i] - quotas[i] as f64;
+    let mut total: u64 = quotas.iter().sum();
+    while total < seats {
+        let bucket = (0..quotas.len())
+            .max_by(|&a, &b| remainder(&quotas, a).total_cmp(&remainder(&quotas, b)))
+            .expect("at least one bucket");
+        quotas[bucket] += 1;
+        total += 1;
+    }
+    while total > seats {
+        let bucket = (0..quotas.len())
+            .filter(|&i| quotas[i] > 0)
+            .min_by(|&a, &b| remainder(&quotas, a).total_cmp(&remainder(&quotas, b)))
+            .expect("a bucket with quota");
+        quotas[bucket] -= 1;
+        total -= 1;
+    }
+    quotas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    
+    #[test]
+    fn apportion_fills_every_seat() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(1);
+        for _ in 0..1000 {
+            let seats = rng.gen_range(0..2000);
+            let targets: Vec<f64> = (0..rng.gen_range(1..8)).map(|_| rng.gen_range(-50.0..1500.0)).collect();
+            // Any targets, including negative ones from buckets already over their share
+            let quotas = apportion(seats, &targets);
+            assert_eq!(quotas.iter().sum::<u64>(), seats, "{:?}", targets);
+            
+            // Targets that add up to the seats are each met within one
+            let sum: f64 = targets.iter().map(|t| t.max(0.0)).sum();
+            if sum > 0.0 {
+                let scaled: Vec<f64> = targets.iter().map(|t| t.max(0.0) * seats as f64 / sum).collect();
+                let quotas = apportion(seats, &scaled);
+                assert_eq!(quotas.iter().sum::<u64>(), seats, "{:?}", scaled);
+                for (quota, target) in quotas.iter().zip(&scaled) {
+                    assert!((*quota as f64 - target).abs() < 1.0, "{:?} -> {:?}", scaled, quotas);
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn block_quotas_track_the_weights() {
+        let weights = vec![1.0, 2.5, 0.0, 7.0];
+        let total: f64 = weights.iter().sum();
+        let count = 12_345;
+        let strata = Strata::new(StratifyBy::PathLength, vec![1, 10, 20, 30, 40], weights.clone(), count, count).unwrap();
+        
+        let mut assigned = vec![0u64; weights.len()];
+        for (block, quotas) in strata.block_quotas.iter().enumerate() {
+            let (start, end) = block_range(count, block as u64);
+            assert_eq!(quotas.iter().sum::<u64>(), end - start);
+            for (i, quota) in quotas.iter().enumerate() {
+                assigned[i] += quota;
+                let target = end as f64 * weights[i] / total;
+                assert!((assigned[i] as f64 - target).abs() <= 1.0, "block {} bucket {}", block, i);
+            }
+        }
+        assert_eq!(assigned[2], 0);
+    }
+    
+    #[test]
+    fn trailing_instance_joins_the_last_block() {
+        let strata = Strata::new(StratifyBy::PathLength, vec![1, 10, 20], vec![], 2001, 4000).unwrap();
+        let sizes: Vec<u64> = strata.block_quotas.iter().map(|quotas| quotas.iter().sum()).collect();
+        assert_eq!(sizes, [1000, 1001]);
+        assert_eq!(strata.block_candidates, 2000);
+    }
+}
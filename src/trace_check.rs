@@ -0,0 +1,533 @@
+use crate::eval::{for_each_chunk, load_predictions, DatasetRecord};
+use crate::parser::{parse_text, rebuild_maze};
+use crate::types::{Maze, ReasoningEvent};
+use crate::SolverType;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// The first event at which a trace stops being a valid execution of its solver
+#[derive(Clone, Debug)]
+pub struct TraceViolation {
+    /// Index of the offending event (equal to the trace length if the trace ends early)
+    pub index: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for TraceViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "event {}: {}", self.index, self.reason)
+    }
+}
+
+const DIRECTIONS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Manhattan distance to the goal
+#[inline(always)]
+fn manhattan(maze: &Maze, x: usize, y: usize) -> u32 {
+    (x.abs_diff(maze.goal.0) + y.abs_diff(maze.goal.1)) as u32
+}
+
+/// Floor cells are the only ones a trace may mention
+fn check_cell(maze: &Maze, x: usize, y: usize) -> Result<(), String> {
+    if x >= maze.cols || y >= maze.rows {
+        return Err(format!("cell {} {} is out of bounds", x, y));
+    }
+    if !maze.get_cell(x, y) {
+        return Err(format!("cell {} {} is a wall", x, y));
+    }
+    Ok(())
+}
+
+/// Replay a trace against the maze and check it is a valid execution of `solver`
+pub fn check_trace(solver: SolverType, maze: &Maze, events: &[ReasoningEvent]) -> Result<(), TraceViolation> {
+    match solver {
+        SolverType::AStar => check_astar_trace(maze, events, manhattan, |g, h| g + h),
+        SolverType::Dijkstra => check_astar_trace(maze, events, |_, _, _| 0, |g, h| g + h),
+        SolverType::Greedy => check_astar_trace(maze, events, manhattan, |_, h| h),
+        SolverType::Bfs => check_bfs_trace(maze, events),
+    }
+}
+
+/// Replay a trace against the maze and check it is a valid A* execution.
+///
+/// Mirrors `astar::solve`: the start node is open with g = 0, every close
+/// pops an open node of minimum f = g + h, and the creates that follow are
+/// exactly the in-bounds floor neighbors whose g improves. Ties between
+/// nodes of equal f may be broken in any order, and so may the creates of
+/// one expansion. The trace must stop once the goal is closed. Dijkstra
+/// traces are checked the same way with h = 0, and greedy best-first
+/// traces with f = h.
+fn check_astar_trace(
+    maze: &Maze,
+    events: &[ReasoningEvent],
+    heuristic: fn(&Maze, usize, usize) -> u32,
+    priority: fn(u32, u32) -> u32,
+) -> Result<(), TraceViolation> {
+    let total_cells = maze.rows * maze.cols;
+    let cell = |x: usize, y: usize| y * maze.cols + x;
+    let violation = |index: usize, reason: String| Err(TraceViolation { index, reason });
+    
+    // Best known g for open cells (u32::MAX = never created)
+    let mut g_scores = vec![u32::MAX; total_cells];
+    let mut closed = vec![false; total_cells];
+    // Lazily cleaned min-heap of (f, cell, g); stale entries are skipped
+    let mut open_set = BinaryHeap::new();
+    
+    let (start_x, start_y) = maze.start;
+    g_scores[cell(start_x, start_y)] = 0;
+    open_set.push(Reverse((priority(0, heuristic(maze, start_x, start_y)), cell(start_x, start_y), 0u32)));
+    
+    // Node being expanded and the neighbors it has created so far
+    let mut expanding: Option<(usize, usize, u32)> = None;
+    let mut created: Vec<(usize, usize)> = Vec::with_capacity(4);
+    let mut goal_closed = false;
+    
+    // Improvable neighbors the current expansion still owes
+    let missing_creates = |expanding: Option<(usize, usize, u32)>,
+                           created: &[(usize, usize)],
+                           g_scores: &[u32],
+                           closed: &[bool]|
+     -> Option<(usize, usize)> {
+        let (x, y, g) = expanding?;
+        DIRECTIONS.iter().find_map(|&(dx, dy)| {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+            if nx < 0 || ny < 0 {
+                return None;
+            }
+            let (nx, ny) = (nx as usize, ny as usize);
+            let owed = maze.get_cell(nx, ny)
+                && !closed[cell(nx, ny)]
+                && g + 1 < g_scores[cell(nx, ny)]
+                && !created.contains(&(nx, ny));
+            owed.then_some((nx, ny))
+        })
+    };
+    
+    for (index, event) in events.iter().enumerate() {
+        if goal_closed {
+            return violation(index, "event after the goal was closed".to_string());
+        }
+        
+        let (x, y, g, h) = match *event {
+            ReasoningEvent::Close { x, y, g, h } | ReasoningEvent::Create { x, y, g, h } => (x, y, g, h),
+            ReasoningEvent::Dequeue { .. } | ReasoningEvent::Enqueue { .. } => {
+                return violation(index, "BFS event in an A* trace".to_string());
+            }
+        };
+        let (x, y) = (x as usize, y as usize);
+        if let Err(reason) = check_cell(maze, x, y) {
+            return violation(index, reason);
+        }
+        let expected_h = heuristic(maze, x, y);
+        if h != expected_h {
+            return violation(index, format!("h of {} {} is {}, expected {}", x, y, h, expected_h));
+        }
+        
+        match event {
+            ReasoningEvent::Close { .. } => {
+                if let Some((nx, ny)) = missing_creates(expanding, &created, &g_scores, &closed) {
+                    let (ex, ey, _) = expanding.unwrap();
+                    return violation(index, format!("expansion of {} {} skipped neighbor {} {}", ex, ey, nx, ny));
+                }
+                
+                // Drop entries that are closed or superseded by a better g
+                while let Some(&Reverse((_, c, entry_g))) = open_set.peek() {
+                    if closed[c] || g_scores[c] != entry_g {
+                        open_set.pop();
+                    } else {
+                        break;
+                    }
+                }
+                let Some(&Reverse((min_f, _, _))) = open_set.peek() else {
+                    return violation(index, "close with an empty open set".to_string());
+                };
+                
+                let c = cell(x, y);
+                if closed[c] {
+                    return violation(index, format!("{} {} is already closed", x, y));
+                }
+                if g_scores[c] == u32::MAX {
+                    return violation(index, format!("{} {} is not in the open set", x, y));
+                }
+                if g != g_scores[c] {
+                    return violation(index, format!("g of {} {} is {}, expected {}", x, y, g, g_scores[c]));
+                }
+                if priority(g, h) != min_f {
+                    return violation(index, format!("f of {} {} is {}, but the open set minimum is {}", x, y, priority(g, h), min_f));
+                }
+                
+                closed[c] = true;
+                goal_closed = (x, y) == maze.goal;
+                expanding = Some((x, y, g));
+                created.clear();
+            }
+            ReasoningEvent::Create { .. } => {
+                let Some((px, py, pg)) = expanding else {
+                    return violation(index, "create before any node was closed".to_string());
+                };
+                if px.abs_diff(x) + py.abs_diff(y) != 1 {
+                    return violation(index, format!("{} {} is not a neighbor of {} {}", x, y, px, py));
+                }
+                let c = cell(x, y);
+                if closed[c] {
+                    return violation(index, format!("{} {} is already closed", x, y));
+                }
+                if g != pg + 1 {
+                    return violation(index, format!("g of {} {} is {}, expected {}", x, y, g, pg + 1));
+                }
+                if g >= g_scores[c] {
+                    return violation(index, format!("{} {} is already open with g {}", x, y, g_scores[c]));
+                }
+                
+                g_scores[c] = g;
+                open_set.push(Reverse((priority(g, h), c, g)));
+                created.push((x, y));
+            }
+            ReasoningEvent::Dequeue { .. } | ReasoningEvent::Enqueue { .. } => unreachable!(),
+        }
+    }
+    
+    if !goal_closed {
+        // A trace without the goal is only complete if the open set ran dry
+        if let Some((nx, ny)) = missing_creates(expanding, &created, &g_scores, &closed) {
+            let (ex, ey, _) = expanding.unwrap();
+            return violation(events.len(), format!("expansion of {} {} skipped neighbor {} {}", ex, ey, nx, ny));
+        }
+        let open_left = open_set
+            .iter()
+            .any(|&Reverse((_, c, entry_g))| !closed[c] && g_scores[c] == entry_g);
+        if open_left {
+            return violation(events.len(), "trace ends before the goal is closed".to_string());
+        }
+    }
+    
+    Ok(())
+}
+
+/// Replay a trace against the maze and check it is a valid BFS execution.
+///
+/// Mirrors `bfs::solve`: the queue starts with the start cell at depth 0,
+/// every dequeue takes the front of the queue, and the enqueues that follow
+/// are exactly the floor neighbors not enqueued before, one level deeper.
+/// The enqueues of one expansion may come in any order, which then fixes
+/// the queue order. The trace must stop once the goal is dequeued.
+fn check_bfs_trace(maze: &Maze, events: &[ReasoningEvent]) -> Result<(), TraceViolation> {
+    let cell = |x: usize, y: usize| y * maze.cols + x;
+    let violation = |index: usize, reason: String| Err(TraceViolation { index, reason });
+    
+    // Depth of every cell enqueued so far (u32::MAX = never enqueued)
+    let mut depths = vec![u32::MAX; maze.rows * maze.cols];
+    let mut queue = VecDeque::new();
+    depths[cell(maze.start.0, maze.start.1)] = 0;
+    queue.push_back(maze.start);
+    
+    // Cell being expanded, with its depth
+    let mut expanding: Option<(usize, usize, u32)> = None;
+    let mut goal_dequeued = false;
+    
+    // Neighbor the current expansion should have enqueued but has not
+    let missing_enqueue = |expanding: Option<(usize, usize, u32)>, depths: &[u32]| -> Option<(usize, usize)> {
+        let (x, y, _) = expanding?;
+        DIRECTIONS.iter().find_map(|&(dx, dy)| {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+            if nx < 0 || ny < 0 {
+                return None;
+            }
+            let (nx, ny) = (nx as usize, ny as usize);
+            let owed = maze.get_cell(nx, ny) && depths[cell(nx, ny)] == u32::MAX;
+            owed.then_some((nx, ny))
+        })
+    };
+    
+    for (index, event) in events.iter().enumerate() {
+        if goal_dequeued {
+            return violation(index, "event after the goal was dequeued".to_string());
+        }
+        
+        let (x, y, depth) = match *event {
+            ReasoningEvent::Dequeue { x, y, depth } | ReasoningEvent::Enqueue { x, y, depth } => (x, y, depth),
+            ReasoningEvent::Close { .. } | ReasoningEvent::Create { .. } => {
+                return violation(index, "A* event in a BFS trace".to_string());
+            }
+        };
+        let (x, y) = (x as usize, y as usize);
+        if let Err(reason) = check_cell(maze, x, y) {
+            return violation(index, reason);
+        }
+        let c = cell(x, y);
+        
+        match event {
+            ReasoningEvent::Dequeue { .. } => {
+                if let Some((nx, ny)) = missing_enqueue(expanding, &depths) {
+                    let (ex, ey, _) = expanding.unwrap();
+                    return violation(index, format!("expansion of {} {} skipped neighbor {} {}", ex, ey, nx, ny));
+                }
+                let Some(&(fx, fy)) = queue.front() else {
+                    return violation(index, "dequeue with an empty queue".to_string());
+                };
+                if (x, y) != (fx, fy) {
+                    return violation(index, format!("dequeued {} {}, but the front of the queue is {} {}", x, y, fx, fy));
+                }
+                if depth != depths[c] {
+                    return violation(index, format!("depth of {} {} is {}, expected {}", x, y, depth, depths[c]));
+                }
+                
+                queue.pop_front();
+                goal_dequeued = (x, y) == maze.goal;
+                expanding = Some((x, y, depth));
+            }
+            ReasoningEvent::Enqueue { .. } => {
+                let Some((px, py, pd)) = expanding else {
+                    return violation(index, "enqueue before any cell was dequeued".to_string());
+                };
+                if px.abs_diff(x) + py.abs_diff(y) != 1 {
+                    return violation(index, format!("{} {} is not a neighbor of {} {}", x, y, px, py));
+                }
+                if depths[c] != u32::MAX {
+                    return violation(index, format!("{} {} was already enqueued", x, y));
+                }
+                if depth != pd + 1 {
+                    return violation(index, format!("depth of {} {} is {}, expected {}", x, y, depth, pd + 1));
+                }
+                
+                depths[c] = depth;
+                queue.push_back((x, y));
+            }
+            ReasoningEvent::Close { .. } | ReasoningEvent::Create { .. } => unreachable!(),
+        }
+    }
+    
+    if !goal_dequeued {
+        // A trace without the goal is only complete if the queue ran dry
+        if let Some((nx, ny)) = missing_enqueue(expanding, &depths) {
+            let (ex, ey, _) = expanding.unwrap();
+            return violation(events.len(), format!("expansion of {} {} skipped neighbor {} {}", ex, ey, nx, ny));
+        }
+        if !queue.is_empty() {
+            return violation(events.len(), "trace ends before the goal is dequeued".to_string());
+        }
+    }
+    
+    Ok(())
+}
+
+/// Per-instance result of `check-traces`
+#[derive(Serialize)]
+struct CheckReport {
+    idx: u64,
+    valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    violation_index: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+/// Check one trace: the dataset's own, or the prediction for this instance
+fn check_record(record: &DatasetRecord, predictions: Option<&HashMap<u64, String>>) -> CheckReport {
+    let result = (|| -> Result<Result<(), TraceViolation>, String> {
+        let parsed = parse_text(&record.text)?;
+        let maze = rebuild_maze(&parsed, record.rows, record.cols)?;
+        let solver = record.solver_type()?;
+        let events = match predictions {
+            Some(predictions) => {
+                let text = predictions.get(&record.idx).ok_or("no prediction")?;
+                parse_text(text)?.reasoning
+            }
+            None => parsed.reasoning,
+        };
+        Ok(check_trace(solver, &maze, &events))
+    })();
+    
+    let (violation_index, reason) = match result {
+        Ok(Ok(())) => (None, None),
+        Ok(Err(violation)) => (Some(violation.index), Some(violation.reason)),
+        Err(error) => (None, Some(error)),
+    };
+    CheckReport {
+        idx: record.idx,
+        valid: reason.is_none(),
+        violation_index,
+        reason,
+    }
+}
+
+/// Check the reasoning traces of a dataset, or of predictions for it.
+/// Writes one report line per instance and prints the number of valid traces.
+pub fn run(dataset_path: &str, predictions_path: Option<&str>, output_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let predictions = predictions_path.map(load_predictions).transpose()?;
+    
+    let mut writer = BufWriter::new(File::create(output_path)?);
+    let mut checked = 0u64;
+    let mut valid = 0u64;
+    
+    for_each_chunk(dataset_path, |chunk| {
+        let reports: Vec<CheckReport> = chunk
+            .par_iter()
+            .map(|record| check_record(record, predictions.as_ref()))
+            .collect();
+        
+        for report in &reports {
+            checked += 1;
+            valid += report.valid as u64;
+            serde_json::to_writer(&mut writer, report)?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(())
+    })?;
+    writer.flush()?;
+    
+    println!(
+        "Valid traces: {}/{} ({:.2}%)",
+        valid,
+        checked,
+        100.0 * valid as f64 / checked.max(1) as f64
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::solve_maze;
+    use ReasoningEvent::{Close, Create, Dequeue, Enqueue};
+    
+    /// Maze from rows of '#' (wall), '.' (floor), 'S' (start) and 'G' (goal)
+    fn maze(rows: &[&str]) -> Maze {
+        let mut maze = Maze::new(rows.len(), rows[0].len());
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                maze.set_cell(x, y, c != '#');
+                match c {
+                    'S' => maze.start = (x, y),
+                    'G' => maze.goal = (x, y),
+                    _ => {}
+                }
+            }
+        }
+        maze
+    }
+    
+    /// Small maze with a loop, so several neighbors are open at once
+    fn looped() -> Maze {
+        maze(&["S...#", ".##..", "....G"])
+    }
+    
+    /// One-row corridor with the start in the middle and a wall at its left end
+    fn corridor() -> Maze {
+        maze(&["#.S.G"])
+    }
+    
+    /// Start's expansion in `corridor()`
+    const CORRIDOR_START: [ReasoningEvent; 3] = [
+        Close { x: 2, y: 0, g: 0, h: 2 },
+        Create { x: 1, y: 0, g: 1, h: 3 },
+        Create { x: 3, y: 0, g: 1, h: 1 },
+    ];
+    
+    /// Start's expansion in `corridor()` as a BFS trace
+    const CORRIDOR_BFS_START: [ReasoningEvent; 3] = [
+        Dequeue { x: 2, y: 0, depth: 0 },
+        Enqueue { x: 1, y: 0, depth: 1 },
+        Enqueue { x: 3, y: 0, depth: 1 },
+    ];
+    
+    fn after(prefix: &[ReasoningEvent], rest: &[ReasoningEvent]) -> Vec<ReasoningEvent> {
+        [prefix, rest].concat()
+    }
+    
+    /// Check each `(name, events, expected_idx, expected_reason)` case is rejected as expected
+    fn assert_violations(solver: SolverType, maze: &Maze, cases: &[(&str, Vec<ReasoningEvent>, usize, &str)]) {
+        for (name, events, index, reason) in cases {
+            let violation = check_trace(solver, maze, events).expect_err(name);
+            assert_eq!(violation.index, *index, "{}: {}", name, violation);
+            assert!(violation.reason.contains(reason), "{}: {}", name, violation);
+        }
+    }
+    
+    #[test]
+    fn solver_traces_are_valid() {
+        let maze = looped();
+        for solver in [SolverType::AStar, SolverType::Dijkstra, SolverType::Greedy, SolverType::Bfs] {
+            let solution = solve_maze(solver, &maze);
+            assert_eq!(check_trace(solver, &maze, &solution.reasoning).map_err(|v| v.to_string()), Ok(()));
+        }
+    }
+    
+    #[test]
+    fn astar_rejects_invalid_traces() {
+        let to_goal = [
+            Close { x: 3, y: 0, g: 1, h: 1 },
+            Create { x: 4, y: 0, g: 2, h: 0 },
+            Close { x: 4, y: 0, g: 2, h: 0 },
+        ];
+        let maze = corridor();
+        assert!(check_trace(SolverType::AStar, &maze, &after(&CORRIDOR_START, &to_goal)).is_ok());
+        
+        assert_violations(SolverType::AStar, &maze, &[
+            ("BFS event", vec![Dequeue { x: 2, y: 0, depth: 0 }], 0, "BFS event in an A* trace"),
+            ("out of bounds", vec![Close { x: 9, y: 0, g: 0, h: 5 }], 0, "out of bounds"),
+            ("wall", vec![Close { x: 0, y: 0, g: 0, h: 4 }], 0, "is a wall"),
+            ("wrong h", vec![Close { x: 2, y: 0, g: 0, h: 3 }], 0, "h of 2 0 is 3, expected 2"),
+            ("event after goal", after(&CORRIDOR_START, &[&to_goal[..], &[Close { x: 1, y: 0, g: 1, h: 3 }]].concat()), 6, "after the goal was closed"),
+            ("close of closed node", after(&CORRIDOR_START, &[Close { x: 2, y: 0, g: 0, h: 2 }]), 3, "already closed"),
+            ("close outside open set", after(&CORRIDOR_START, &[Close { x: 4, y: 0, g: 2, h: 0 }]), 3, "not in the open set"),
+            ("close with wrong g", after(&CORRIDOR_START, &[Close { x: 3, y: 0, g: 2, h: 1 }]), 3, "g of 3 0 is 2, expected 1"),
+            ("close out of priority order", after(&CORRIDOR_START, &[Close { x: 1, y: 0, g: 1, h: 3 }]), 3, "open set minimum"),
+            ("missing create", after(&CORRIDOR_START[..2], &[Close { x: 1, y: 0, g: 1, h: 3 }]), 2, "skipped neighbor 3 0"),
+            ("create before close", vec![Create { x: 1, y: 0, g: 1, h: 3 }], 0, "create before any node was closed"),
+            ("create of non-neighbor", after(&CORRIDOR_START[..1], &[Create { x: 4, y: 0, g: 1, h: 0 }]), 1, "not a neighbor"),
+            ("create with wrong g", after(&CORRIDOR_START[..2], &[Create { x: 3, y: 0, g: 2, h: 1 }]), 2, "g of 3 0 is 2, expected 1"),
+            ("create of closed node", after(&CORRIDOR_START, &[to_goal[0], Create { x: 2, y: 0, g: 2, h: 2 }]), 4, "already closed"),
+            ("create without improvement", after(&CORRIDOR_START, &[Create { x: 3, y: 0, g: 1, h: 1 }]), 3, "already open with g 1"),
+            ("ends before goal", CORRIDOR_START.to_vec(), 3, "ends before the goal is closed"),
+            ("ends mid expansion", CORRIDOR_START[..2].to_vec(), 2, "skipped neighbor 3 0"),
+        ]);
+    }
+    
+    #[test]
+    fn bfs_rejects_invalid_traces() {
+        let to_goal = [
+            Dequeue { x: 1, y: 0, depth: 1 },
+            Dequeue { x: 3, y: 0, depth: 1 },
+            Enqueue { x: 4, y: 0, depth: 2 },
+            Dequeue { x: 4, y: 0, depth: 2 },
+        ];
+        let maze = corridor();
+        assert!(check_trace(SolverType::Bfs, &maze, &after(&CORRIDOR_BFS_START, &to_goal)).is_ok());
+        
+        assert_violations(SolverType::Bfs, &maze, &[
+            ("A* event", vec![Close { x: 2, y: 0, g: 0, h: 2 }], 0, "A* event in a BFS trace"),
+            ("out of bounds", vec![Dequeue { x: 0, y: 3, depth: 0 }], 0, "out of bounds"),
+            ("wall", vec![Dequeue { x: 0, y: 0, depth: 0 }], 0, "is a wall"),
+            ("event after goal", after(&CORRIDOR_BFS_START, &[&to_goal[..], &[Dequeue { x: 1, y: 0, depth: 1 }]].concat()), 7, "after the goal was dequeued"),
+            ("dequeue with wrong depth", vec![Dequeue { x: 2, y: 0, depth: 1 }], 0, "depth of 2 0 is 1, expected 0"),
+            ("dequeue out of queue order", after(&CORRIDOR_BFS_START, &[to_goal[1]]), 3, "front of the queue"),
+            ("missing enqueue", after(&CORRIDOR_BFS_START[..2], &[to_goal[0]]), 2, "skipped neighbor 3 0"),
+            ("enqueue before dequeue", vec![Enqueue { x: 1, y: 0, depth: 1 }], 0, "enqueue before any cell was dequeued"),
+            ("enqueue of non-neighbor", after(&CORRIDOR_BFS_START[..1], &[Enqueue { x: 4, y: 0, depth: 1 }]), 1, "not a neighbor"),
+            ("enqueue with wrong depth", after(&CORRIDOR_BFS_START[..2], &[Enqueue { x: 3, y: 0, depth: 2 }]), 2, "depth of 3 0 is 2, expected 1"),
+            ("repeated enqueue", after(&CORRIDOR_BFS_START, &[to_goal[0], Enqueue { x: 2, y: 0, depth: 2 }]), 4, "already enqueued"),
+            ("ends before goal", CORRIDOR_BFS_START.to_vec(), 3, "ends before the goal is dequeued"),
+            ("ends mid expansion", CORRIDOR_BFS_START[..2].to_vec(), 2, "skipped neighbor 3 0"),
+        ]);
+    }
+    
+    #[test]
+    fn rejects_expansion_past_an_empty_frontier() {
+        // The start has no open neighbor, so the goal is unreachable
+        let maze = maze(&["S#G"]);
+        assert_violations(SolverType::AStar, &maze, &[
+            ("empty open set", vec![Close { x: 0, y: 0, g: 0, h: 2 }, Close { x: 0, y: 0, g: 0, h: 2 }], 1, "empty open set"),
+        ]);
+        assert_violations(SolverType::Bfs, &maze, &[
+            ("empty queue", vec![Dequeue { x: 0, y: 0, depth: 0 }, Dequeue { x: 0, y: 0, depth: 0 }], 1, "empty queue"),
+        ]);
+    }
+}
@@ -0,0 +1,352 @@
+use crate::parameters::{default_transform_rate, GeneratorParams, DEFAULT_PERTURB_MAGNITUDE};
+use crate::blocks::{block_of, block_range};
+use crate::prng::{create_swap_prng, create_transform_prng};
+use crate::types::{Maze, MazeResult, ReasoningEvent};
+use crate::{SolverType, TransformType};
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+/// Donor assignment for one block of instance ids
+pub struct SwapBlock {
+    pub start: u64,
+    pub donors: Vec<u64>,
+}
+
+impl SwapBlock {
+    /// Build the seed-derived derangement for a block.
+    /// Uses Sattolo's algorithm, so the permutation is a single cycle and
+    /// no instance is ever paired with its own trace.
+    pub fn new(master_seed: u64, count: u64, block: u64) -> Self {
+        let (start, end) = block_range(count, block);
+        let len = (end - start) as usize;
+        
+        let mut rng = create_swap_prng(master_seed, block);
+        let mut donors: Vec<u64> = (start..end).collect();
+        for i in (1..len).rev() {
+            let j = rng.gen_range(0..i);
+            donors.swap(i, j);
+        }
+        
+        SwapBlock { start, donors }
+    }
+    
+    #[inline(always)]
+    pub fn donor(&self, instance_id: u64) -> u64 {
+        self.donors[(instance_id - self.start) as usize]
+    }
+}
+
+/// Replace each result's reasoning with the trace of its donor instance.
+/// `results` must hold consecutive instance ids; donors outside the batch
+/// are rebuilt with `regenerate`.
+pub fn swap_traces<F>(
+    results: &mut [MazeResult],
+    master_seed: u64,
+    count: u64,
+    regenerate: F,
+) -> Result<(), String>
+where
+    F: Fn(u64) -> Result<MazeResult, String>,
+{
+    let Some(first) = results.first() else {
+        return Ok(());
+    };
+    let batch_start = first.instance_id;
+    let batch_end = batch_start + results.len() as u64;
+    
+    // Take the original traces out first so every donor is read unswapped
+    let mut originals: Vec<Option<Vec<ReasoningEvent>>> = results
+        .iter_mut()
+        .map(|result| Some(std::mem::take(&mut result.solution.reasoning)))
+        .collect();
+    
+    let mut block: Option<SwapBlock> = None;
+    for result in results.iter_mut() {
+        let block_idx = block_of(count, result.instance_id);
+        let current = match block {
+            Some(ref b) if block_of(count, b.start) == block_idx => b,
+            _ => block.insert(SwapBlock::new(master_seed, count, block_idx)),
+        };
+        let donor = current.donor(result.instance_id);
+        
+        let trace = if (batch_start..batch_end).contains(&donor) {
+            originals[(donor - batch_start) as usize].take()
+        } else {
+            None
+        };
+        result.solution.reasoning = match trace {
+            Some(trace) => trace,
+            None => regenerate(donor)?.solution.reasoning,
+        };
+        result.trace_donor = Some(donor);
+    }
+    Ok(())
+}
+
+/// A corruption applied to reasoning traces after solving
+#[derive(Clone, Copy, Debug)]
+pub struct TraceTransform {
+    pub transform: TransformType,
+    pub rate: f64,
+    pub magnitude: i64,
+}
+
+impl TraceTransform {
+    /// Resolve a transform's parameters, falling back to the documented defaults
+    pub fn new(transform: TransformType, params: &GeneratorParams) -> Self {
+        TraceTransform {
+            transform,
+            rate: params.get("rate", default_transform_rate(transform)),
+            magnitude: params.get_int("magnitude", DEFAULT_PERTURB_MAGNITUDE),
+        }
+    }
+    
+    /// Apply the transform to a result's trace using its instance PRNG stream
+    pub fn apply(&self, result: &mut MazeResult) {
+        let mut rng = create_transform_prng(result.seed, result.instance_id);
+        let reasoning = &mut result.solution.reasoning;
+        let affected = (reasoning.len() as f64 * self.rate).round() as usize;
+        
+        match self.transform {
+            TransformType::None => {}
+            TransformType::Shuffle => {
+                // Permute the events at a random subset of positions
+                let mut positions = index::sample(&mut rng, reasoning.len(), affected).into_vec();
+                positions.sort_unstable();
+                let mut events: Vec<ReasoningEvent> = positions.iter().map(|&i| reasoning[i]).collect();
+                events.shuffle(&mut rng);
+                for (&i, event) in positions.iter().zip(events) {
+                    reasoning[i] = event;
+                }
+            }
+            TransformType::Drop => {
+                let mut keep = vec![true; reasoning.len()];
+                for i in index::sample(&mut rng, reasoning.len(), affected) {
+                    keep[i] = false;
+                }
+                let mut keep = keep.into_iter();
+                reasoning.retain(|_| keep.next().unwrap());
+            }
+            TransformType::Truncate => {
+                reasoning.truncate(reasoning.len() - affected);
+            }
+            TransformType::Perturb => {
+                let magnitude = self.magnitude.clamp(1, i32::MAX as i64) as i32;
+                for i in index::sample(&mut rng, reasoning.len(), affected) {
+                    // Nonzero offset in [-magnitude, magnitude]
+                    let mut delta = rng.gen_range(1..=magnitude);
+                    if rng.gen::<bool>() {
+                        delta = -delta;
+                    }
+                    let perturb_h = rng.gen::<bool>();
+                    let value = match &mut reasoning[i] {
+                        ReasoningEvent::Close { g, h, .. } | ReasoningEvent::Create { g, h, .. } => {
+                            if perturb_h { h } else { g }
+                        }
+                        ReasoningEvent::Dequeue { depth, .. } | ReasoningEvent::Enqueue { depth, .. } => depth,
+                    };
+                    *value = (*value as i64 + delta as i64).clamp(0, u32::MAX as i64) as u32;
+                }
+            }
+            TransformType::Inject => {
+                let max_g = reasoning
+                    .iter()
+                    .map(|event| match *event {
+                        ReasoningEvent::Close { g, .. } | ReasoningEvent::Create { g, .. } => g,
+                        ReasoningEvent::Dequeue { depth, .. } | ReasoningEvent::Enqueue { depth, .. } => depth,
+                    })
+                    .max()
+                    .unwrap_or(0);
+                for _ in 0..affected {
+                    let event = random_event(&mut rng, &result.maze, result.solver, max_g);
+                    let pos = rng.gen_range(0..=reasoning.len());
+                    reasoning.insert(pos, event);
+                }
+            }
+        }
+    }
+}
+
+/// Random event in the solver's vocabulary at any in-bounds cell, with the
+/// solver's h (Manhattan, or 0 for Dijkstra) and a g (or depth) of at most `max_g`
+fn random_event(
+    rng: &mut Xoshiro256PlusPlus,
+    maze: &Maze,
+    solver: SolverType,
+    max_g: u32,
+) -> ReasoningEvent {
+    let x = rng.gen_range(0..maze.cols);
+    let y = rng.gen_range(0..maze.rows);
+    let h = (x.abs_diff(maze.goal.0) + y.abs_diff(maze.goal.1)) as u32;
+    let g = rng.gen_range(0..=max_g);
+    let (x, y) = (x as u32, y as u32);
+    
+    let first = rng.gen::<bool>();
+    match solver {
+        SolverType::AStar | SolverType::Greedy if first => ReasoningEvent::Close { x, y, g, h },
+        SolverType::AStar | SolverType::Greedy => ReasoningEvent::Create { x, y, g, h },
+        SolverType::Dijkstra if first => ReasoningEvent::Close { x, y, g, h: 0 },
+        SolverType::Dijkstra => ReasoningEvent::Create { x, y, g, h: 0 },
+        SolverType::Bfs if first => ReasoningEvent::Dequeue { x, y, depth: g },
+        SolverType::Bfs => ReasoningEvent::Enqueue { x, y, depth: g },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GeneratorType, PlacementType, SeedScheme};
+    use crate::generators::{generate_maze, wall_bounds};
+    use crate::placement::Placement;
+    use crate::solvers::solve_maze;
+    use crate::types::Solution;
+    use rand::SeedableRng;
+    
+    /// Result whose one-event trace names its own instance id
+    fn result(instance_id: u64) -> MazeResult {
+        MazeResult {
+            instance_id,
+            maze: Maze::new(1, 1),
+            solution: Solution {
+                path: vec![],
+                reasoning: vec![ReasoningEvent::Close { x: instance_id as u32, y: 0, g: 0, h: 0 }],
+            },
+            generator: GeneratorType::Dfs,
+            solver: SolverType::AStar,
+            seed: 0,
+            seed_scheme: SeedScheme::V1,
+            placement: PlacementType::Uniform,
+            candidate: None,
+            plan_optimal: None,
+            trace_donor: None,
+            trace_transform: None,
+        }
+    }
+    
+    fn swapped(start: u64, end: u64, count: u64) -> Vec<MazeResult> {
+        let mut results: Vec<MazeResult> = (start..end).map(result).collect();
+        swap_traces(&mut results, 7, count, |donor| Ok(result(donor))).unwrap();
+        results
+    }
+    
+    #[test]
+    fn swap_blocks_are_single_cycles() {
+        for count in [2, 3, 1000, 1001, 2001, 2500] {
+            for block in 0..=block_of(count, count - 1) {
+                let swap = SwapBlock::new(7, count, block);
+                let (start, end) = block_range(count, block);
+                
+                // Following donors from the first instance visits the whole block once
+                let mut seen = vec![false; (end - start) as usize];
+                let mut idx = start;
+                for _ in start..end {
+                    assert!(!seen[(idx - start) as usize], "count {} block {}", count, block);
+                    seen[(idx - start) as usize] = true;
+                    let donor = swap.donor(idx);
+                    assert_ne!(donor, idx, "count {} block {}", count, block);
+                    idx = donor;
+                }
+                assert_eq!(idx, start, "count {} block {}", count, block);
+            }
+        }
+    }
+    
+    #[test]
+    fn split_batches_match_the_unsplit_swap() {
+        let count = 2001;
+        let whole = swapped(0, count, count);
+        let split: Vec<MazeResult> = [(0, 700), (700, 1500), (1500, count)]
+            .into_iter()
+            .flat_map(|(start, end)| swapped(start, end, count))
+            .collect();
+        
+        for (a, b) in whole.iter().zip(&split) {
+            let donor = a.trace_donor.unwrap();
+            assert_eq!(b.trace_donor, Some(donor));
+            assert_eq!(a.solution.reasoning, b.solution.reasoning);
+            assert_eq!(a.solution.reasoning, result(donor).solution.reasoning);
+        }
+    }
+    
+    /// A solved 21x21 DFS maze
+    fn solved(solver: SolverType) -> MazeResult {
+        let params = GeneratorParams::default();
+        let placement = Placement::new(PlacementType::Uniform, &params).unwrap();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(3);
+        let maze = generate_maze(GeneratorType::Dfs, &mut rng, 21, 21, &params, &placement, wall_bounds(21, 21, &params, false)).unwrap();
+        MazeResult { solution: solve_maze(solver, &maze), maze, solver, ..result(5) }
+    }
+    
+    /// Apply a transform to a solved maze's trace, returning the original and transformed traces
+    fn transformed(transform: TransformType, solver: SolverType, rate: f64) -> (Vec<ReasoningEvent>, Vec<ReasoningEvent>) {
+        let mut result = solved(solver);
+        let original = result.solution.reasoning.clone();
+        let transform = TraceTransform { transform, rate, magnitude: 3 };
+        transform.apply(&mut result);
+        (original, result.solution.reasoning)
+    }
+    
+    /// Whether `sub` appears in `events` in order
+    fn is_subsequence(sub: &[ReasoningEvent], events: &[ReasoningEvent]) -> bool {
+        let mut events = events.iter();
+        sub.iter().all(|event| events.any(|e| e == event))
+    }
+    
+    fn affected(len: usize, rate: f64) -> usize {
+        (len as f64 * rate).round() as usize
+    }
+    
+    #[test]
+    fn shuffle_permutes_events() {
+        let (original, shuffled) = transformed(TransformType::Shuffle, SolverType::AStar, 1.0);
+        assert_ne!(original, shuffled);
+        let sorted = |events: &[ReasoningEvent]| {
+            let mut keys: Vec<String> = events.iter().map(|e| format!("{:?}", e)).collect();
+            keys.sort();
+            keys
+        };
+        assert_eq!(sorted(&original), sorted(&shuffled));
+    }
+    
+    #[test]
+    fn drop_removes_a_fraction_of_events_in_order() {
+        let (original, dropped) = transformed(TransformType::Drop, SolverType::AStar, 0.3);
+        assert_eq!(dropped.len(), original.len() - affected(original.len(), 0.3));
+        assert!(is_subsequence(&dropped, &original));
+    }
+    
+    #[test]
+    fn truncate_keeps_a_prefix() {
+        let (original, truncated) = transformed(TransformType::Truncate, SolverType::Bfs, 0.5);
+        assert_eq!(truncated.len(), original.len() - affected(original.len(), 0.5));
+        assert_eq!(truncated[..], original[..truncated.len()]);
+    }
+    
+    #[test]
+    fn perturb_changes_values_by_at_most_the_magnitude() {
+        let (original, perturbed) = transformed(TransformType::Perturb, SolverType::AStar, 0.2);
+        assert_eq!(perturbed.len(), original.len());
+        let changed = original.iter().zip(&perturbed).filter(|(a, b)| a != b).count();
+        assert!(changed > 0 && changed <= affected(original.len(), 0.2));
+        for (a, b) in original.iter().zip(&perturbed) {
+            match (*a, *b) {
+                (ReasoningEvent::Close { x, y, g, h }, ReasoningEvent::Close { x: bx, y: by, g: bg, h: bh })
+                | (ReasoningEvent::Create { x, y, g, h }, ReasoningEvent::Create { x: bx, y: by, g: bg, h: bh }) => {
+                    assert_eq!((x, y), (bx, by));
+                    assert!(g.abs_diff(bg) <= 3 && h.abs_diff(bh) <= 3);
+                    assert!(g == bg || h == bh, "only one of g and h changes");
+                }
+                _ => panic!("perturb changed an event's kind: {:?} -> {:?}", a, b),
+            }
+        }
+    }
+    
+    #[test]
+    fn inject_inserts_events_in_the_solver_vocabulary() {
+        let (original, injected) = transformed(TransformType::Inject, SolverType::Bfs, 0.25);
+        assert_eq!(injected.len(), original.len() + affected(original.len(), 0.25));
+        assert!(is_subsequence(&original, &injected));
+        assert!(injected.iter().all(|e| matches!(e, ReasoningEvent::Dequeue { .. } | ReasoningEvent::Enqueue { .. })));
+    }
+}
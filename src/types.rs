@@ -11,7 +11,7 @@ pub struct Maze {
 impl Maze {
     /// Create a new maze with all walls
     pub fn new(rows: usize, cols: usize) -> Self {
-        let cols_bytes = (cols + 7) / 8;
+        let cols_bytes = cols.div_ceil(8);
         Maze {
             grid: vec![0u8; rows * cols_bytes],
             start: (0, 0),
@@ -49,10 +49,14 @@ impl Maze {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ReasoningEvent {
-    Close { x: u16, y: u16, g: u16, h: u16 },
-    Create { x: u16, y: u16, g: u16, h: u16 },
+    Close { x: u32, y: u32, g: u32, h: u32 },
+    Create { x: u32, y: u32, g: u32, h: u32 },
+    /// BFS: a cell leaves the queue
+    Dequeue { x: u32, y: u32, depth: u32 },
+    /// BFS: a cell joins the queue
+    Enqueue { x: u32, y: u32, depth: u32 },
 }
 
 #[derive(Clone, Debug)]
@@ -68,4 +72,10 @@ pub struct MazeResult {
     pub generator: crate::GeneratorType,
     pub solver: crate::SolverType,
     pub seed: u64,
+    pub seed_scheme: crate::SeedScheme,
+    pub placement: crate::PlacementType,
+    pub candidate: Option<u64>,  // Candidate id that seeded a stratified instance
+    pub plan_optimal: Option<bool>,  // Plan is shortest (only for solvers without that guarantee)
+    pub trace_donor: Option<u64>,  // Instance whose reasoning was swapped in
+    pub trace_transform: Option<crate::traces::TraceTransform>,
 }
\ No newline at end of file
@@ -0,0 +1,135 @@
+use crate::generators::{generate_maze, wall_bounds};
+use crate::parameters::GeneratorParams;
+use crate::placement::Placement;
+use crate::prng::create_instance_prng;
+use crate::solvers::solve_maze;
+use crate::types::Maze;
+use crate::{GeneratorType, PlacementType, SeedScheme, SolverType};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Grid size the samplers are compared on (small enough to enumerate trees)
+const SIZE: usize = 5;
+
+/// Mazes sampled from each generator
+const SAMPLES: u64 = 20_000;
+
+/// Master seed of the samples
+const SEED: u64 = 0;
+
+/// Bins with fewer pooled observations than this are merged into one bin
+const MIN_BIN: u64 = 10;
+
+/// One-sided z above which two distributions are reported as different (p < 0.0005)
+const Z_CRITICAL: f64 = 3.29;
+
+/// Statistics of one sampled maze
+struct Sample {
+    /// Bit mask of the floor cells, identifying the spanning tree
+    tree: u64,
+    dead_ends: u64,
+    path_length: u64,
+}
+
+fn sample(generator: GeneratorType, seed: u64, instance_id: u64) -> Result<Sample, String> {
+    let mut rng = create_instance_prng(SeedScheme::V1, seed, generator, instance_id);
+    let params = GeneratorParams::default();
+    let placement = Placement::new(PlacementType::Uniform, &params)?;
+    let maze = generate_maze(generator, &mut rng, SIZE, SIZE, &params, &placement, wall_bounds(SIZE, SIZE, &params, false))?;
+    let solution = solve_maze(SolverType::AStar, &maze);
+    
+    let mut tree = 0u64;
+    let mut dead_ends = 0u64;
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            if maze.get_cell(x, y) {
+                tree |= 1 << (y * SIZE + x);
+                dead_ends += (floor_neighbors(&maze, x, y) == 1) as u64;
+            }
+        }
+    }
+    Ok(Sample { tree, dead_ends, path_length: solution.path.len() as u64 })
+}
+
+fn floor_neighbors(maze: &Maze, x: usize, y: usize) -> usize {
+    [(0i32, -1i32), (1, 0), (0, 1), (-1, 0)]
+        .iter()
+        .filter(|&&(dx, dy)| {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+            nx >= 0 && ny >= 0 && (nx as usize) < SIZE && (ny as usize) < SIZE
+                && maze.get_cell(nx as usize, ny as usize)
+        })
+        .count()
+}
+
+/// Two-sample chi-square test on histograms keyed by value.
+/// Returns the statistic, its degrees of freedom and the Wilson-Hilferty z score.
+fn chi_square(a: &BTreeMap<u64, u64>, b: &BTreeMap<u64, u64>) -> (f64, usize, f64) {
+    let total_a: u64 = a.values().sum();
+    let total_b: u64 = b.values().sum();
+    let ka = (total_b as f64 / total_a as f64).sqrt();
+    let kb = (total_a as f64 / total_b as f64).sqrt();
+    
+    let mut bins = Vec::new();
+    let mut pooled = (0u64, 0u64);
+    for key in a.keys().chain(b.keys()).collect::<BTreeSet<_>>() {
+        let pair = (a.get(key).copied().unwrap_or(0), b.get(key).copied().unwrap_or(0));
+        if pair.0 + pair.1 < MIN_BIN {
+            pooled = (pooled.0 + pair.0, pooled.1 + pair.1);
+        } else {
+            bins.push(pair);
+        }
+    }
+    if pooled.0 + pooled.1 > 0 {
+        bins.push(pooled);
+    }
+    
+    let stat: f64 = bins
+        .iter()
+        .map(|&(x, y)| (ka * x as f64 - kb * y as f64).powi(2) / (x + y) as f64)
+        .sum();
+    let df = bins.len().saturating_sub(1).max(1);
+    let k = df as f64;
+    let z = ((stat / k).cbrt() - (1.0 - 2.0 / (9.0 * k))) / (2.0 / (9.0 * k)).sqrt();
+    (stat, df, z)
+}
+
+/// Compare the Wilson and Aldous-Broder samplers on 5x5 grids.
+///
+/// Both should draw uniform spanning trees, so the distributions of the
+/// tree itself, of dead-end counts and of solution path lengths must agree.
+/// Fails if any two-sample chi-square test rejects at p < 0.0005.
+/// Slow in debug builds, so run it with `cargo test --release -- --ignored`.
+#[test]
+#[ignore]
+fn wilson_and_aldous_broder_agree() {
+    let generators = [GeneratorType::Wilson, GeneratorType::AldousBroder];
+    let mut histograms: Vec<[BTreeMap<u64, u64>; 3]> = Vec::new();
+    for generator in generators {
+        let results: Vec<Sample> = (0..SAMPLES)
+            .into_par_iter()
+            .map(|instance_id| sample(generator, SEED, instance_id))
+            .collect::<Result<_, String>>()
+            .unwrap();
+        
+        let mut hist: [BTreeMap<u64, u64>; 3] = Default::default();
+        for s in &results {
+            *hist[0].entry(s.tree).or_insert(0) += 1;
+            *hist[1].entry(s.dead_ends).or_insert(0) += 1;
+            *hist[2].entry(s.path_length).or_insert(0) += 1;
+        }
+        histograms.push(hist);
+    }
+    
+    let mut failed = Vec::new();
+    for (i, name) in ["spanning tree", "dead ends", "path length"].iter().enumerate() {
+        let (stat, df, z) = chi_square(&histograms[0][i], &histograms[1][i]);
+        let verdict = if z > Z_CRITICAL { "DIFFERENT" } else { "ok" };
+        println!("{:<14} chi2 = {:>9.2}  df = {:>4}  z = {:>6.2}  {}", name, stat, df, z, verdict);
+        if z > Z_CRITICAL {
+            failed.push(*name);
+        }
+    }
+    assert!(failed.is_empty(), "wilson and aldous-broder differ in: {}", failed.join(", "));
+}